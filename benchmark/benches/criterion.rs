@@ -0,0 +1,115 @@
+//! Benchmark every problem of a contest on its own input, or only some of them, e.g.
+//! `CONTEST=september-2025 PROBLEMS=kl cargo bench`. Without `CONTEST`, the contest is the one
+//! the current directory is in, or else the latest.
+//!
+//! Besides solving the whole input in one go, each problem gets its parsing, every case and its
+//! formatting timed on their own, in the `parse`, `solve_case` and `format` groups, so that e.g.
+//! `cargo bench -- parse/` only benchmarks parsing. With `--features count-allocations`, what each
+//! problem allocates is printed first.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use common::{Solver, Stages};
+
+/// The problems named by the `PROBLEMS` environment variable, or all of them if it is unset.
+fn selected() -> Vec<&'static dyn Solver> {
+    let contest = std::env::var("CONTEST").ok();
+    let contest = benchmark::contest(contest.as_deref()).unwrap_or_else(|err| panic!("{err}"));
+    let letters: Vec<char> = std::env::var("PROBLEMS")
+        .unwrap_or_default()
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .collect();
+    contest
+        .select(&letters)
+        .unwrap_or_else(|letter| panic!("PROBLEMS names no such problem: {letter:?}"))
+}
+
+fn solve(problem: &dyn Solver) -> String {
+    problem.solve_str(problem.input()).expect("invalid input")
+}
+
+pub fn ev_benchmark_full(c: &mut Criterion) {
+    let problems = selected();
+    if common::counting_allocations() {
+        for &problem in &problems {
+            let run = problem.run_timed(problem.input()).expect("invalid input");
+            if let Some(allocations) = run.allocations {
+                println!("problem_{}: {allocations}", problem.letter());
+            }
+        }
+    }
+    for &problem in &problems {
+        let name = format!("problem_{}", problem.letter());
+        c.bench_function(&name, |b| b.iter(|| black_box(solve(problem))));
+    }
+    if problems.len() > 1 {
+        c.bench_function("all", |b| {
+            b.iter(|| {
+                for &problem in &problems {
+                    black_box(solve(problem));
+                }
+            })
+        });
+    }
+}
+
+/// Benchmarks each step a problem hands over in its own group.
+struct Steps<'a> {
+    c: &'a mut Criterion,
+    name: String,
+}
+
+impl Stages for Steps<'_> {
+    fn parse(&mut self, parse: &mut dyn FnMut()) {
+        let mut group = self.c.benchmark_group("parse");
+        group.bench_function(&self.name, |b| b.iter(&mut *parse));
+        group.finish();
+    }
+
+    fn prepare(&mut self, prepare: &mut dyn FnMut()) {
+        let mut group = self.c.benchmark_group("prepare");
+        // Problem b's takes minutes.
+        group.sample_size(10);
+        group.bench_function(&self.name, |b| b.iter(&mut *prepare));
+        group.finish();
+    }
+
+    fn solve_case(&mut self, case: usize, solve: &mut dyn FnMut()) {
+        let mut group = self.c.benchmark_group("solve_case");
+        // Some cases take seconds, so take as few samples as criterion allows.
+        group.sample_size(10);
+        group.bench_function(BenchmarkId::new(&self.name, case), |b| b.iter(&mut *solve));
+        group.finish();
+    }
+
+    fn format(&mut self, format: &mut dyn FnMut()) {
+        let mut group = self.c.benchmark_group("format");
+        group.bench_function(&self.name, |b| b.iter(&mut *format));
+        group.finish();
+    }
+}
+
+pub fn ev_benchmark_stages(c: &mut Criterion) {
+    for problem in selected() {
+        let mut steps = Steps {
+            c: &mut *c,
+            name: format!("problem_{}", problem.letter()),
+        };
+        problem
+            .run_stages(problem.input(), &mut steps)
+            .expect("invalid input");
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = ev_benchmark_full, ev_benchmark_stages
+}
+
+criterion_main! {
+    benches
+}
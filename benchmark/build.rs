@@ -0,0 +1,115 @@
+//! Build the problem registry from the workspace: every `CONTEST/problem-*` member, in the order
+//! the workspace lists them, is compiled into this crate as a module named `CONTEST_problem_*`,
+//! so its dependencies other than `common` must also be dependencies of this crate. Every
+//! `problem-*` crate in a contest's directory must be such a member.
+
+use std::path::Path;
+
+use toml_edit::DocumentMut;
+
+fn read_manifest(path: &Path) -> DocumentMut {
+    println!("cargo::rerun-if-changed={}", path.display());
+    std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+        .parse()
+        .unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+}
+
+/// The names of a manifest's dependencies.
+fn dependencies(manifest: &DocumentMut) -> Vec<&str> {
+    manifest
+        .get("dependencies")
+        .and_then(|dependencies| dependencies.as_table_like())
+        .map(|dependencies| dependencies.iter().map(|(name, _)| name).collect())
+        .unwrap_or_default()
+}
+
+fn main() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let workspace_dir = dir.parent().unwrap();
+    let workspace = read_manifest(&workspace_dir.join("Cargo.toml"));
+    let manifest = read_manifest(&dir.join("Cargo.toml"));
+    let available = dependencies(&manifest);
+
+    let members = workspace["workspace"]["members"]
+        .as_array()
+        .expect("workspace.members is not an array");
+    // Contests in the order their first problem appears, each with its problems' modules in order.
+    let mut contests: Vec<(&str, Vec<String>)> = Vec::new();
+    let mut modules = String::new();
+    for member in members.iter().filter_map(|member| member.as_str()) {
+        let Some((contest, problem)) = member.split_once('/') else {
+            continue;
+        };
+        if !problem.starts_with("problem-") {
+            continue;
+        }
+
+        let problem_manifest = read_manifest(&workspace_dir.join(member).join("Cargo.toml"));
+        for dependency in dependencies(&problem_manifest) {
+            if !available.contains(&dependency) {
+                panic!(
+                    "{member} depends on {dependency}, which benchmark compiles it with but does \
+                     not depend on; add it with `cargo add --package benchmark {dependency}`"
+                );
+            }
+        }
+
+        // Named after their contest too, so that contests can share letters.
+        let module = format!("{contest}_{problem}").replace('-', "_");
+        let lib = workspace_dir.join(member).join("src").join("lib.rs");
+        println!("cargo::rerun-if-changed={}", lib.display());
+        modules.push_str(&format!(
+            "#[path = {:?}]\npub mod {module};\n",
+            lib.display().to_string()
+        ));
+        match contests.iter_mut().find(|(other, _)| *other == contest) {
+            Some((_, problems)) => problems.push(module),
+            None => contests.push((contest, vec![module])),
+        }
+    }
+
+    // A problem crate that is no member would silently be left out of the registry.
+    let mut contest_names: Vec<&str> = workspace["workspace"]["metadata"]
+        .as_table_like()
+        .map(|metadata| metadata.iter().map(|(contest, _)| contest).collect())
+        .unwrap_or_default();
+    contest_names.extend(contests.iter().map(|&(contest, _)| contest));
+    for contest in contest_names {
+        let contest_dir = workspace_dir.join(contest);
+        println!("cargo::rerun-if-changed={}", contest_dir.display());
+        let Ok(entries) = std::fs::read_dir(&contest_dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let problem = entry.file_name().to_string_lossy().into_owned();
+            let member = format!("{contest}/{problem}");
+            if problem.starts_with("problem-")
+                && entry.path().join("Cargo.toml").exists()
+                && !members.iter().any(|other| other.as_str() == Some(&member))
+            {
+                panic!(
+                    "{member} is a problem crate but not a workspace member, so benchmark can't \
+                     register it; add it to `workspace.members` in Cargo.toml"
+                );
+            }
+        }
+    }
+
+    let contests: Vec<String> = contests
+        .iter()
+        .map(|(contest, problems)| {
+            let idents: Vec<String> = problems
+                .iter()
+                .map(|problem| format!("        {problem},\n"))
+                .collect();
+            format!("    {contest:?} => [\n{}    ],\n", idents.concat())
+        })
+        .collect();
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("problems.rs");
+    std::fs::write(
+        out,
+        format!("{modules}\ncontests!(\n{});\n", contests.concat()),
+    )
+    .unwrap();
+}
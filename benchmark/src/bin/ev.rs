@@ -0,0 +1,323 @@
+//! Run some or all of the problems on their inputs, save their outputs and report where the time
+//! went.
+
+use std::cmp::Reverse;
+use std::fmt::Write;
+use std::io;
+use std::iter::zip;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use checker::Report;
+use clap::Parser;
+
+use benchmark::cli;
+use common::{Allocations, Budget, Bytes, TimedRun};
+
+#[derive(Parser)]
+#[command(about = "Run problems, save their outputs and print how long they took")]
+struct Args {
+    /// Letters of the problems to run, e.g. `a c f`.
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    problems: Vec<char>,
+
+    /// Run every problem.
+    #[arg(long)]
+    all: bool,
+
+    /// Contest the problems are in, e.g. `september-2025`. Defaults to the one the current
+    /// directory is in, or else the latest.
+    #[arg(long)]
+    contest: Option<String>,
+
+    /// Directory the outputs are written to, one `problem-X.txt` per problem. Defaults to
+    /// `target/ev/CONTEST` in the workspace.
+    #[arg(long)]
+    output_dir: Option<PathBuf>,
+
+    /// Check each output against the problem's `output.txt`.
+    #[arg(long)]
+    check: bool,
+
+    /// Print every case of every problem run, slowest first, and save the same as `profile.csv`
+    /// in the output directory.
+    #[arg(long)]
+    profile: bool,
+
+    /// Threads to solve cases on; 1 solves them one after the other. Defaults to one per core.
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Seconds each case of a search-based problem may take before settling for the best answer
+    /// found so far.
+    #[arg(long, value_parser = parse_seconds)]
+    time_limit: Option<Duration>,
+
+    /// Search nodes each case of a search-based problem may visit before settling for the best
+    /// answer found so far.
+    #[arg(long)]
+    node_limit: Option<u64>,
+
+    /// Log what the solvers are doing to stderr: `-v` for a summary of each case, `-vv` for their
+    /// searches as well.
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+fn parse_seconds(arg: &str) -> Result<Duration, String> {
+    let secs: f64 = arg.parse().map_err(|err| format!("{err}"))?;
+    Duration::try_from_secs_f64(secs).map_err(|err| format!("{err}"))
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let Some(threads) = args.threads {
+        common::set_threads(threads);
+    }
+    common::set_verbosity(args.verbose);
+    common::show_progress(true);
+    common::set_budget(Budget {
+        time: args.time_limit,
+        nodes: args.node_limit,
+    });
+
+    // With `--all`, `args.problems` is empty and every problem is selected.
+    let (contest, problems) = match cli::problems(args.contest.as_deref(), &args.problems) {
+        Ok(found) => found,
+        Err(err) => return cli::usage_error(err),
+    };
+
+    let output_dir = args.output_dir.unwrap_or_else(|| {
+        benchmark::workspace_dir()
+            .join("target")
+            .join("ev")
+            .join(contest.name)
+    });
+    if let Err(err) = std::fs::create_dir_all(&output_dir) {
+        eprintln!("error: {}: {err}", output_dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut runs = Vec::new();
+    let mut failed = false;
+    for problem in problems {
+        let letter = problem.letter();
+        let run = match problem.run_timed(problem.input()) {
+            Ok(run) => run,
+            Err(err) => {
+                eprintln!("error: problem {letter}: {err}");
+                failed = true;
+                continue;
+            }
+        };
+
+        let path = output_dir.join(format!("problem-{letter}.txt"));
+        if let Err(err) = std::fs::write(&path, &run.output) {
+            eprintln!("error: {}: {err}", path.display());
+            failed = true;
+        }
+
+        let report = if args.check {
+            let path = contest.problem_dir(letter).join("output.txt");
+            match std::fs::read_to_string(&path) {
+                Ok(expected) => {
+                    let report = problem
+                        .check_output(problem.input(), &expected, &run.output)
+                        .expect("input already parsed");
+                    failed |= !report.is_accepted();
+                    Some(report)
+                }
+                Err(err) => {
+                    eprintln!("error: {}: {err}", path.display());
+                    failed = true;
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        print_cases(letter, &run, report.as_ref());
+        runs.push((letter, run, report));
+    }
+
+    print_summary(&runs);
+
+    if args.profile {
+        let path = output_dir.join("profile.csv");
+        if let Err(err) = print_profile(&runs, &path) {
+            eprintln!("error: {}: {err}", path.display());
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fmt(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+/// Extra columns with what something allocated, only shown while allocations are counted.
+fn allocation_columns(allocations: Option<Allocations>) -> String {
+    if !common::counting_allocations() {
+        return String::new();
+    }
+    match allocations {
+        Some(allocations) => format!(
+            " {:>10} {:>12} {:>12}",
+            allocations.count,
+            Bytes(allocations.bytes).to_string(),
+            Bytes(allocations.peak).to_string()
+        ),
+        None => format!(" {:>10} {:>12} {:>12}", "", "", ""),
+    }
+}
+
+fn allocation_header() -> String {
+    if common::counting_allocations() {
+        format!(" {:>10} {:>12} {:>12}", "allocs", "allocated", "peak")
+    } else {
+        String::new()
+    }
+}
+
+fn print_cases(letter: char, run: &TimedRun, report: Option<&Report>) {
+    let verdict = |case| {
+        let verdict = report
+            .and_then(|report| report.cases.iter().find(|&&(n, _)| n == case))
+            .map(|(_, verdict)| verdict.to_string())
+            .unwrap_or_default();
+        if run.timed_out.contains(&case) {
+            format!("{verdict} (timed out)").trim_start().to_owned()
+        } else {
+            verdict
+        }
+    };
+
+    println!(
+        "{:<8} {:>6} {:>12}{}  verdict",
+        "problem",
+        "case",
+        "time",
+        allocation_header()
+    );
+    for (i, (&time, &allocations)) in zip(&run.cases, &run.case_allocations).enumerate() {
+        println!(
+            "{letter:<8} {:>6} {:>12}{}  {}",
+            format!("#{}", i + 1),
+            fmt(time),
+            allocation_columns(allocations),
+            verdict(i + 1)
+        );
+    }
+
+    // Cases the checker saw that the solver did not produce, e.g. ones missing from the output.
+    for (case, verdict) in report.into_iter().flat_map(|report| &report.cases) {
+        if !(1..=run.cases.len()).contains(case) {
+            println!(
+                "{letter:<8} {:>6} {:>12}{}  {verdict}",
+                format!("#{case}"),
+                "",
+                allocation_columns(None)
+            );
+        }
+    }
+    println!();
+}
+
+fn print_summary(runs: &[(char, TimedRun, Option<Report>)]) {
+    println!(
+        "{:<8} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>9}{}  accepted",
+        "problem",
+        "cases",
+        "parse",
+        "prepare",
+        "solve",
+        "format",
+        "total",
+        "timed out",
+        allocation_header()
+    );
+    for (letter, run, report) in runs {
+        let accepted = report
+            .as_ref()
+            .map(|report| format!("{}/{}", report.accepted(), report.cases.len()))
+            .unwrap_or_default();
+        println!(
+            "{letter:<8} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>9}{}  {accepted}",
+            run.cases.len(),
+            fmt(run.parse),
+            fmt(run.prepare),
+            fmt(run.solve),
+            fmt(run.format),
+            fmt(run.total()),
+            run.timed_out.len(),
+            allocation_columns(run.allocations),
+        );
+    }
+    if runs.len() > 1 {
+        let total: Duration = runs.iter().map(|(_, run, _)| run.total()).sum();
+        println!("{:<8} {:>6} {:>12}", "all", "", fmt(total));
+    }
+}
+
+/// Print every case slowest first, along with its size, and write the same rows to `csv_path`.
+/// Each problem's [`Problem::prepare`](common::Problem::prepare) step gets a row of its own, with
+/// `prepare` for its case, since no case's time includes it. Allocation columns are left empty
+/// unless allocations are being counted.
+fn print_profile(runs: &[(char, TimedRun, Option<Report>)], csv_path: &Path) -> io::Result<()> {
+    type Row = (char, String, Option<usize>, Duration, Option<Allocations>);
+    let mut cases: Vec<Row> = runs
+        .iter()
+        .flat_map(|(letter, run, _)| {
+            let prepare = (*letter, "prepare".to_owned(), None, run.prepare, None);
+            let cases = zip(zip(&run.sizes, &run.cases), &run.case_allocations)
+                .enumerate()
+                .map(|(i, ((&size, &time), &allocations))| {
+                    (*letter, format!("#{}", i + 1), size, time, allocations)
+                });
+            std::iter::once(prepare).chain(cases)
+        })
+        .collect();
+    cases.sort_by_key(|&(.., time, _)| Reverse(time));
+
+    let optional = |value: Option<u64>| value.map(|value| value.to_string()).unwrap_or_default();
+    let mut csv = String::from("problem,case,size,nanoseconds,allocations,bytes,peak_bytes\n");
+    println!();
+    println!(
+        "{:<8} {:>6} {:>12} {:>12}{}",
+        "problem",
+        "case",
+        "size",
+        "time",
+        allocation_header()
+    );
+    for (letter, case, case_size, time, allocations) in &cases {
+        let (case_size, time, allocations) =
+            (case_size.map(|size| size as u64), *time, *allocations);
+        println!(
+            "{letter:<8} {case:>6} {:>12} {:>12}{}",
+            optional(case_size),
+            fmt(time),
+            allocation_columns(allocations)
+        );
+        writeln!(
+            csv,
+            "{letter},{},{},{},{},{},{}",
+            case.trim_start_matches('#'),
+            optional(case_size),
+            time.as_nanos(),
+            optional(allocations.map(|allocations| allocations.count)),
+            optional(allocations.map(|allocations| allocations.bytes)),
+            optional(allocations.map(|allocations| allocations.peak)),
+        )
+        .unwrap();
+    }
+    std::fs::write(csv_path, csv)
+}
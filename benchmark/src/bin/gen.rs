@@ -0,0 +1,64 @@
+//! Generate a random input for a problem, e.g. `gen f --seed 3 max_width=8 cases=5`.
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use benchmark::{Contest, cli};
+
+#[derive(Parser)]
+#[command(about = "Generate a random, reproducible input for a problem")]
+struct Args {
+    /// Letter of the problem to generate an input for.
+    problem: char,
+
+    /// Contest the problem is in, e.g. `september-2025`. Defaults to the one the current directory
+    /// is in, or else the latest.
+    #[arg(long)]
+    contest: Option<String>,
+
+    /// Seed for the random number generator; the same seed gives the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// List the problem's constraints and their defaults, followed by the limits its solver
+    /// supports, instead of generating an input.
+    #[arg(long)]
+    list: bool,
+
+    /// Constraints to override, as `KEY=VALUE`.
+    #[arg(value_parser = cli::parse_constraint)]
+    constraints: Vec<(String, String)>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let (_, problem) = match cli::problem(
+        args.contest.as_deref(),
+        args.problem,
+        Contest::find_generator,
+    ) {
+        Ok(found) => found,
+        Err(err) => return cli::usage_error(err),
+    };
+
+    if args.list {
+        for (key, default) in problem.default_constraints() {
+            println!("{key}={default}");
+        }
+        for limit in problem.limits() {
+            println!("# {limit}");
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let constraints = cli::constraints(&args.constraints);
+    match problem.generate_input(args.seed, &constraints) {
+        Ok(input) => {
+            println!("{input}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => cli::usage_error(err),
+    }
+}
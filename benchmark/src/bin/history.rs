@@ -0,0 +1,192 @@
+//! Record how long problems take at the current commit in `benchmark/history/CONTEST.csv`, or
+//! compare the current tree against a recorded commit, e.g.
+//! `history compare --all --baseline HEAD~1`.
+
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+
+use benchmark::cli;
+use benchmark::history::{self, History, Record};
+use common::Solver;
+
+#[derive(Parser)]
+#[command(about = "Keep benchmark results per commit and check for regressions against them")]
+struct Cli {
+    #[command(subcommand)]
+    command: Task,
+}
+
+#[derive(Subcommand)]
+enum Task {
+    /// Time problems and record their medians for the current commit.
+    Record(Run),
+
+    /// Time problems and fail if any is slower than at a recorded commit by more than a threshold.
+    Compare {
+        #[command(flatten)]
+        run: Run,
+
+        /// Commit to compare against, e.g. `HEAD~1`. Defaults to the latest one recorded, other
+        /// than the current one.
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Slowdown in percent beyond which a problem counts as regressed.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+#[derive(Args)]
+struct Run {
+    /// Letters of the problems to time, e.g. `a c f`.
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    problems: Vec<char>,
+
+    /// Time every problem.
+    #[arg(long)]
+    all: bool,
+
+    /// Contest the problems are in, e.g. `september-2025`. Defaults to the one the current
+    /// directory is in, or else the latest.
+    #[arg(long)]
+    contest: Option<String>,
+
+    /// How many times to run each problem; the median is kept.
+    #[arg(long, default_value_t = 5)]
+    runs: usize,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+fn run(task: Task) -> Result<ExitCode> {
+    let run = match &task {
+        Task::Record(run) | Task::Compare { run, .. } => run,
+    };
+    let (contest, problems) = cli::problems(run.contest.as_deref(), &run.problems)?;
+    let path = History::path(contest.name);
+    let mut history = History::load(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let commit = history::current_commit()?;
+
+    match task {
+        Task::Record(run) => {
+            let records = measure(&problems, &commit, &run)?;
+            print_records(&records);
+            for record in records {
+                history.record(record);
+            }
+            history
+                .save(&path)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            println!("recorded as {} in {}", short(&commit), path.display());
+            Ok(ExitCode::SUCCESS)
+        }
+
+        Task::Compare {
+            run,
+            baseline,
+            threshold,
+        } => {
+            let baseline = match baseline {
+                Some(rev) => history::resolve_commit(&rev)?,
+                None => history
+                    .latest_before(&commit)
+                    .ok_or("no other commit recorded to compare against")?
+                    .to_owned(),
+            };
+            let recorded: Vec<&Record> = history.at(&baseline).collect();
+            if recorded.is_empty() {
+                return Err(format!("nothing recorded for {}", short(&baseline)).into());
+            }
+
+            let records = measure(&problems, &commit, &run)?;
+            let regressions = history::regressions(&recorded, &records, threshold / 100.0);
+            if regressions.is_empty() {
+                println!(
+                    "no problem is more than {threshold}% slower than at {}",
+                    short(&baseline)
+                );
+                return Ok(ExitCode::SUCCESS);
+            }
+
+            println!(
+                "{:<8} {:>12} {:>12} {:>9}",
+                "problem", "baseline", "current", "slowdown"
+            );
+            for regression in &regressions {
+                println!(
+                    "{:<8} {:>12} {:>12} {:>8.1}%",
+                    regression.problem,
+                    fmt(regression.baseline),
+                    fmt(regression.current),
+                    regression.slowdown() * 100.0
+                );
+            }
+            println!(
+                "{} problem(s) more than {threshold}% slower than at {}",
+                regressions.len(),
+                short(&baseline)
+            );
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Time every problem `run.runs` times, returning their medians as records of `commit`.
+fn measure(problems: &[&dyn Solver], commit: &str, run: &Run) -> Result<Vec<Record>> {
+    if run.runs == 0 {
+        return Err("--runs must be at least 1".into());
+    }
+
+    let mut records = Vec::new();
+    for problem in problems {
+        let mut times = Vec::with_capacity(run.runs);
+        for _ in 0..run.runs {
+            let timed = problem
+                .run_timed(problem.input())
+                .map_err(|err| format!("problem {}: {err}", problem.letter()))?;
+            times.push(timed.total());
+        }
+        records.push(Record {
+            commit: commit.to_owned(),
+            problem: problem.letter(),
+            median: history::median(times),
+            runs: run.runs,
+        });
+    }
+    Ok(records)
+}
+
+fn print_records(records: &[Record]) {
+    println!("{:<8} {:>12} {:>6}", "problem", "median", "runs");
+    for record in records {
+        println!(
+            "{:<8} {:>12} {:>6}",
+            record.problem,
+            fmt(record.median),
+            record.runs
+        );
+    }
+}
+
+fn fmt(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+/// Enough of a commit hash to recognize it, keeping any `-dirty` suffix.
+fn short(commit: &str) -> String {
+    let (hash, suffix) = commit.split_at(commit.find('-').unwrap_or(commit.len()));
+    format!("{}{suffix}", &hash[..hash.len().min(10)])
+}
@@ -0,0 +1,115 @@
+//! Look for generated inputs a problem fails on, shrink the first one found and save it as a
+//! regression sample, e.g. `stress f --runs 500 max_height=3 max_width=4`.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use benchmark::{Contest, cli};
+
+#[derive(Parser)]
+#[command(about = "Find a failing input for a problem, minimize it and save it as a regression")]
+struct Args {
+    /// Letter of the problem to stress.
+    problem: char,
+
+    /// Contest the problem is in, e.g. `september-2025`. Defaults to the one the current directory
+    /// is in, or else the latest.
+    #[arg(long)]
+    contest: Option<String>,
+
+    /// Seed of the first generated input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How many inputs to generate before giving up.
+    #[arg(long, default_value_t = 100)]
+    runs: u64,
+
+    /// Minimize this failing input instead of generating new ones.
+    #[arg(long, conflicts_with_all = ["seed", "runs", "constraints"])]
+    input: Option<PathBuf>,
+
+    /// Constraints to generate inputs with, as `KEY=VALUE`; see `gen --list`.
+    #[arg(value_parser = cli::parse_constraint)]
+    constraints: Vec<(String, String)>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let (contest, problem) = match cli::problem(
+        args.contest.as_deref(),
+        args.problem,
+        Contest::find_shrinker,
+    ) {
+        Ok(found) => found,
+        Err(err) => return cli::usage_error(err),
+    };
+
+    let (name, input, failure) = if let Some(path) = &args.input {
+        let input = match std::fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+        let Some(failure) = problem.failure_of(&input) else {
+            eprintln!(
+                "error: {}: problem {} does not fail on it",
+                path.display(),
+                problem.letter()
+            );
+            return ExitCode::FAILURE;
+        };
+        let name = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        (name, input, failure)
+    } else {
+        let constraints = cli::constraints(&args.constraints);
+
+        let mut found = None;
+        for seed in args.seed..args.seed + args.runs {
+            let input = match problem.generate_input(seed, &constraints) {
+                Ok(input) => input,
+                Err(err) => return cli::usage_error(err),
+            };
+            if let Some(failure) = problem.failure_of(&input) {
+                found = Some((format!("seed-{seed}"), input, failure));
+                break;
+            }
+        }
+        let Some(found) = found else {
+            println!(
+                "problem {} passed {} generated inputs",
+                problem.letter(),
+                args.runs
+            );
+            return ExitCode::SUCCESS;
+        };
+        found
+    };
+
+    println!("problem {} {failure}", problem.letter());
+    let minimized = problem.minimize_input(&input, &failure);
+
+    let dir = contest.regressions_dir(problem.letter());
+    let path = dir.join(format!("{name}.txt"));
+    if let Err(err) = std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&path, &minimized))
+    {
+        eprintln!("error: {}: {err}", path.display());
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "minimized from {} to {} lines, saved to {}:\n{minimized}",
+        input.lines().count(),
+        minimized.lines().count(),
+        path.display()
+    );
+    ExitCode::FAILURE
+}
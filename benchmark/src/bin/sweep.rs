@@ -0,0 +1,182 @@
+//! Estimate how a problem's solver scales by timing it on generated inputs of growing size, e.g.
+//! `sweep m --key max_cuts --from 2 --to 24` or `sweep k --key max_organizers --key max_sockets`.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+
+use benchmark::{Contest, cli, complexity};
+
+#[derive(Parser)]
+#[command(about = "Time a problem on generated inputs of growing size and fit a complexity class")]
+struct Args {
+    /// Letter of the problem to sweep.
+    problem: char,
+
+    /// Contest the problem is in, e.g. `september-2025`. Defaults to the one the current directory
+    /// is in, or else the latest.
+    #[arg(long)]
+    contest: Option<String>,
+
+    /// Constraint set to each size of the sweep; see `gen --list`. Can be given several times.
+    #[arg(long = "key", required = true)]
+    keys: Vec<String>,
+
+    /// Smallest size.
+    #[arg(long, default_value_t = 1)]
+    from: u64,
+
+    /// Largest size.
+    #[arg(long, default_value_t = 64)]
+    to: u64,
+
+    /// How much each size grows over the last.
+    #[arg(long, default_value_t = 2.0)]
+    factor: f64,
+
+    /// Inputs generated per size, with seeds from 0.
+    #[arg(long, default_value_t = 3)]
+    seeds: u64,
+
+    /// Seconds after which a size is the last one tried, so that slow solvers don't run forever.
+    #[arg(long, default_value_t = 10.0)]
+    stop_after: f64,
+
+    /// Where to write every case's size and time as CSV. Defaults to
+    /// `target/sweep/CONTEST/problem-X.csv` in the workspace.
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Other constraints to generate inputs with, as `KEY=VALUE`.
+    #[arg(value_parser = cli::parse_constraint)]
+    constraints: Vec<(String, String)>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let (contest, problem) = match cli::problem(
+        args.contest.as_deref(),
+        args.problem,
+        Contest::find_generator,
+    ) {
+        Ok(found) => found,
+        Err(err) => return cli::usage_error(err),
+    };
+    // Cases solved side by side slow each other down, which would skew their times.
+    common::set_threads(1);
+
+    let sizes = complexity::geometric(args.from, args.to, args.factor);
+    let mut points = Vec::new();
+    let mut csv = String::from("sweep,seed,case,size,nanoseconds\n");
+
+    println!(
+        "{:>8} {:>6} {:>12} {:>12}",
+        "sweep", "cases", "median", "total"
+    );
+    for size in sizes {
+        let size_value = size.to_string();
+        let mut constraints = cli::constraints(&args.constraints);
+        constraints.extend(
+            args.keys
+                .iter()
+                .map(|key| (key.as_str(), size_value.as_str())),
+        );
+
+        let start = Instant::now();
+        let mut times = Vec::new();
+        for seed in 0..args.seeds {
+            let input = match problem.generate_input(seed, &constraints) {
+                Ok(input) => input,
+                Err(err) => return cli::usage_error(err),
+            };
+            let run = match problem.run_timed(&input) {
+                Ok(run) => run,
+                Err(err) => {
+                    eprintln!("error: size {size}, seed {seed}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            for (i, (&time, &case_size)) in run.cases.iter().zip(&run.sizes).enumerate() {
+                // Generators pick sizes up to the constraint, so prefer the case's actual size.
+                let case_size = case_size.map_or(size, |case_size| case_size as u64);
+                points.push((case_size as f64, time.as_secs_f64()));
+                times.push(time);
+                writeln!(
+                    csv,
+                    "{size},{seed},{},{case_size},{}",
+                    i + 1,
+                    time.as_nanos()
+                )
+                .unwrap();
+            }
+        }
+        let elapsed = start.elapsed();
+
+        times.sort();
+        let median = times.get(times.len() / 2).copied().unwrap_or_default();
+        println!(
+            "{size:>8} {:>6} {:>12} {:>12}",
+            times.len(),
+            fmt(median),
+            fmt(elapsed)
+        );
+        if elapsed.as_secs_f64() > args.stop_after {
+            println!("stopping after {}s", args.stop_after);
+            break;
+        }
+    }
+
+    let path = args.output.unwrap_or_else(|| {
+        benchmark::workspace_dir()
+            .join("target")
+            .join("sweep")
+            .join(contest.name)
+            .join(format!("problem-{}.csv", problem.letter()))
+    });
+    if let Err(err) = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| std::fs::write(&path, csv))
+    {
+        eprintln!("error: {}: {err}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    if points.is_empty() {
+        eprintln!("error: no cases were solved");
+        return ExitCode::FAILURE;
+    }
+    let fits = complexity::fit(&points);
+    println!();
+    println!(
+        "{:<12} {:>12} {:>14} {:>8}",
+        "class", "overhead", "coefficient", "error"
+    );
+    for fit in &fits {
+        println!(
+            "{:<12} {:>12} {:>14.3e} {:>7.1}%",
+            fit.class.to_string(),
+            fmt(Duration::from_secs_f64(fit.overhead)),
+            fit.coefficient,
+            fit.error * 100.0
+        );
+    }
+    println!();
+    match complexity::exponent(&points) {
+        Some(exponent) => println!("time grows like n^{exponent:.2} over the sweep"),
+        None => println!("need cases of at least two sizes to estimate an exponent"),
+    }
+    if let Some(best) = fits.first() {
+        println!("best fit: {}", best.class);
+    }
+    println!("saved to {}", path.display());
+    ExitCode::SUCCESS
+}
+
+fn fmt(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
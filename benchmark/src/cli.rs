@@ -0,0 +1,58 @@
+//! Argument handling shared by the binaries.
+
+use std::fmt::Display;
+use std::process::ExitCode;
+
+use common::Solver;
+
+use crate::Contest;
+
+/// Parse a `KEY=VALUE` constraint argument.
+pub fn parse_constraint(arg: &str) -> Result<(String, String), String> {
+    let (key, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, found {arg:?}"))?;
+    Ok((key.to_owned(), value.to_owned()))
+}
+
+/// Parsed constraints, borrowed the way [`Generator::generate_input`](common::Generator::generate_input) takes them.
+pub fn constraints(constraints: &[(String, String)]) -> Vec<(&str, &str)> {
+    constraints
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect()
+}
+
+/// The contest named by `--contest`, see [`contest`](crate::contest), and its problem `letter`,
+/// looked up with `find`, e.g. [`Contest::find_generator`].
+pub fn problem<T>(
+    contest: Option<&str>,
+    letter: char,
+    find: impl FnOnce(&'static Contest, char) -> Option<T>,
+) -> Result<(&'static Contest, T), String> {
+    let contest = crate::contest(contest)?;
+    let problem = find(contest, letter).ok_or_else(|| no_such_problem(contest, letter))?;
+    Ok((contest, problem))
+}
+
+/// Like [`problem`], but for every problem in `letters`, or every problem if it is empty.
+pub fn problems(
+    contest: Option<&str>,
+    letters: &[char],
+) -> Result<(&'static Contest, Vec<&'static dyn Solver>), String> {
+    let contest = crate::contest(contest)?;
+    let problems = contest
+        .select(letters)
+        .map_err(|letter| no_such_problem(contest, letter))?;
+    Ok((contest, problems))
+}
+
+fn no_such_problem(contest: &Contest, letter: char) -> String {
+    format!("{}: no such problem: {letter:?}", contest.name)
+}
+
+/// Report a mistake in the arguments, exiting with 2 like clap does.
+pub fn usage_error(err: impl Display) -> ExitCode {
+    eprintln!("error: {err}");
+    ExitCode::from(2)
+}
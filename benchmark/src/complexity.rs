@@ -0,0 +1,175 @@
+//! Guessing how a solver scales from how long it takes on cases of different sizes.
+
+use std::fmt;
+
+/// A common complexity class, as a function of the case size `n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+    Exponential,
+}
+
+impl Class {
+    pub const ALL: [Class; 7] = [
+        Class::Constant,
+        Class::Logarithmic,
+        Class::Linear,
+        Class::Linearithmic,
+        Class::Quadratic,
+        Class::Cubic,
+        Class::Exponential,
+    ];
+
+    /// The class's function at `n`. Logarithms are kept at 1 or more, so that cases of size 1 or
+    /// 2 don't make them vanish.
+    pub fn eval(self, n: f64) -> f64 {
+        let log = n.log2().max(1.0);
+        match self {
+            Class::Constant => 1.0,
+            Class::Logarithmic => log,
+            Class::Linear => n,
+            Class::Linearithmic => n * log,
+            Class::Quadratic => n * n,
+            Class::Cubic => n * n * n,
+            Class::Exponential => n.exp2(),
+        }
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Class::Constant => "O(1)",
+            Class::Logarithmic => "O(log n)",
+            Class::Linear => "O(n)",
+            Class::Linearithmic => "O(n log n)",
+            Class::Quadratic => "O(n^2)",
+            Class::Cubic => "O(n^3)",
+            Class::Exponential => "O(2^n)",
+        })
+    }
+}
+
+/// How well `time ≈ overhead + coefficient × class(n)` explains a set of measurements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub class: Class,
+    /// Seconds spent whatever the size, e.g. on setting up a search.
+    pub overhead: f64,
+    /// Seconds per unit of the class's function.
+    pub coefficient: f64,
+    /// Root mean square of the relative errors, e.g. `0.1` if predictions are off by about 10%.
+    pub error: f64,
+}
+
+impl Fit {
+    /// The predicted time at size `n`, in seconds.
+    pub fn predict(&self, n: f64) -> f64 {
+        self.overhead + self.coefficient * self.class.eval(n)
+    }
+}
+
+/// The median time of each size in `(size, seconds)` measurements, smallest size first.
+pub fn medians(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    points
+        .chunk_by(|a, b| a.0 == b.0)
+        .map(|same| same[same.len() / 2])
+        .collect()
+}
+
+/// Fit `(size, seconds)` measurements to every [`Class`], best fit first.
+///
+/// Measurements are reduced to the median of each size first, so that every size counts the
+/// same. Each fit then minimizes the relative rather than the absolute error, so that the few
+/// slowest sizes don't drown out the rest.
+pub fn fit(points: &[(f64, f64)]) -> Vec<Fit> {
+    let points: Vec<(f64, f64)> = medians(points)
+        .into_iter()
+        .filter(|&(_, time)| time > 0.0)
+        .collect();
+
+    let mut fits: Vec<Fit> = Class::ALL
+        .into_iter()
+        .map(|class| {
+            let (overhead, coefficient) = least_squares(&points, class);
+            let error = (points
+                .iter()
+                .map(|&(n, time)| ((overhead + coefficient * class.eval(n)) / time - 1.0).powi(2))
+                .sum::<f64>()
+                / points.len() as f64)
+                .sqrt();
+            Fit {
+                class,
+                overhead,
+                coefficient,
+                error: if error.is_finite() { error } else { f64::MAX },
+            }
+        })
+        .collect();
+    fits.sort_by(|a, b| a.error.total_cmp(&b.error));
+    fits
+}
+
+/// The overhead and coefficient minimizing `Σ ((a + c f(n) - t) / t)²`, with neither negative.
+fn least_squares(points: &[(f64, f64)], class: Class) -> (f64, f64) {
+    // Weighted least squares with weights 1/t², solving the 2×2 normal equations.
+    let (mut sw, mut swf, mut swff, mut swt, mut swft) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for &(n, time) in points {
+        let (w, f) = (1.0 / (time * time), class.eval(n));
+        sw += w;
+        swf += w * f;
+        swff += w * f * f;
+        swt += w * time;
+        swft += w * f * time;
+    }
+    let det = sw * swff - swf * swf;
+    if det > f64::EPSILON * sw * swff {
+        let overhead = (swff * swt - swf * swft) / det;
+        let coefficient = (sw * swft - swf * swt) / det;
+        if overhead >= 0.0 && coefficient >= 0.0 {
+            return (overhead, coefficient);
+        }
+    }
+    // Without an overhead, or if the best one would be negative.
+    (0.0, (swft / swff).max(0.0))
+}
+
+/// The slope of `log time` against `log size`, i.e. `k` if the time grows like `n^k`, or `None`
+/// with fewer than two distinct sizes.
+pub fn exponent(points: &[(f64, f64)]) -> Option<f64> {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|&&(n, time)| n > 0.0 && time > 0.0)
+        .map(|&(n, time)| (n.ln(), time.ln()))
+        .collect();
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|&(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|&(_, y)| y).sum::<f64>() / count;
+    let covariance: f64 = logs.iter().map(|&(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = logs.iter().map(|&(x, _)| (x - mean_x).powi(2)).sum();
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+/// Sizes from `from` up to `to`, each `factor` times the last, rounded and without repeats.
+pub fn geometric(from: u64, to: u64, factor: f64) -> Vec<u64> {
+    let mut sizes = Vec::new();
+    let mut size = from.max(1) as f64;
+    while size.round() as u64 <= to {
+        let rounded = size.round() as u64;
+        if sizes.last() != Some(&rounded) {
+            sizes.push(rounded);
+        }
+        if factor <= 1.0 {
+            break;
+        }
+        size *= factor;
+    }
+    sizes
+}
@@ -0,0 +1,204 @@
+//! Median times of every problem, recorded per git commit in `benchmark/history/CONTEST.csv` so
+//! that runs can be compared across commits.
+
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::workspace_dir;
+
+/// The median time of one problem at one commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Full hash of the commit, followed by `-dirty` if the tree had uncommitted changes.
+    pub commit: String,
+    pub problem: char,
+    pub median: Duration,
+    /// How many runs the median is over.
+    pub runs: usize,
+}
+
+/// Every [`Record`] kept, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub records: Vec<Record>,
+}
+
+const HEADER: &str = "commit,problem,median_nanoseconds,runs";
+
+/// Where every contest's history is kept.
+fn dir() -> PathBuf {
+    workspace_dir().join("benchmark").join("history")
+}
+
+impl History {
+    /// Where the history of a contest is kept in the repository.
+    pub fn path(contest: &str) -> PathBuf {
+        dir().join(format!("{contest}.csv"))
+    }
+
+    /// Read the history at `path`, which is empty if there is no file yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(csv) => Self::parse(&csv),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(csv: &str) -> io::Result<Self> {
+        let invalid = |n: usize, what: &str| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("line {n}: {what}"))
+        };
+
+        let mut records = Vec::new();
+        for (i, line) in csv.lines().enumerate().skip(1) {
+            let n = i + 1;
+            let fields: Vec<&str> = line.split(',').collect();
+            let &[commit, problem, median, runs] = fields.as_slice() else {
+                return Err(invalid(n, "expected 4 fields"));
+            };
+            let mut problem = problem.chars();
+            let (Some(problem), None) = (problem.next(), problem.next()) else {
+                return Err(invalid(n, "problem should be a single letter"));
+            };
+            records.push(Record {
+                commit: commit.to_owned(),
+                problem,
+                median: Duration::from_nanos(
+                    median.parse().map_err(|_| invalid(n, "invalid median"))?,
+                ),
+                runs: runs.parse().map_err(|_| invalid(n, "invalid run count"))?,
+            });
+        }
+        Ok(Self { records })
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{HEADER}\n");
+        for record in &self.records {
+            writeln!(
+                csv,
+                "{},{},{},{}",
+                record.commit,
+                record.problem,
+                record.median.as_nanos(),
+                record.runs
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_csv())
+    }
+
+    /// Add a record, replacing any earlier one of the same problem at the same commit.
+    pub fn record(&mut self, record: Record) {
+        self.records
+            .retain(|old| !(old.commit == record.commit && old.problem == record.problem));
+        self.records.push(record);
+    }
+
+    /// The records of a commit.
+    pub fn at<'a>(&'a self, commit: &'a str) -> impl Iterator<Item = &'a Record> {
+        self.records
+            .iter()
+            .filter(move |record| record.commit == commit)
+    }
+
+    /// The most recently recorded commit other than `commit`.
+    pub fn latest_before(&self, commit: &str) -> Option<&str> {
+        self.records
+            .iter()
+            .rev()
+            .map(|record| record.commit.as_str())
+            .find(|&other| other != commit)
+    }
+}
+
+/// A problem whose median time went up by more than the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub problem: char,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// How much slower the current run is, e.g. `0.25` for 25%.
+    pub fn slowdown(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0
+    }
+}
+
+/// The problems in `current` that are more than `threshold` (e.g. `0.1` for 10%) slower than in
+/// `baseline`. Problems missing from `baseline` are skipped.
+pub fn regressions(baseline: &[&Record], current: &[Record], threshold: f64) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|current| {
+            let baseline = baseline
+                .iter()
+                .find(|baseline| baseline.problem == current.problem)?;
+            let regression = Regression {
+                problem: current.problem,
+                baseline: baseline.median,
+                current: current.median,
+            };
+            (regression.slowdown() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+/// The median of `times`, which must not be empty.
+pub fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+fn git(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("git")
+        .current_dir(workspace_dir())
+        .args(args)
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "git {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// The full hash of a revision, e.g. `HEAD~1`.
+pub fn resolve_commit(rev: &str) -> io::Result<String> {
+    git(&["rev-parse", "--verify", &format!("{rev}^{{commit}}")])
+}
+
+/// What the current tree is recorded as: `HEAD`, marked dirty if anything but the histories
+/// themselves has changed since.
+pub fn current_commit() -> io::Result<String> {
+    let head = resolve_commit("HEAD")?;
+    let history = dir();
+    let history = history.strip_prefix(workspace_dir()).unwrap();
+    let changes = git(&[
+        "status",
+        "--porcelain",
+        "--",
+        ".",
+        &format!(":!{}", history.display()),
+    ])?;
+    Ok(if changes.is_empty() {
+        head
+    } else {
+        format!("{head}-dirty")
+    })
+}
@@ -0,0 +1,114 @@
+use std::path::{Path, PathBuf};
+
+use common::{Generator, Shrinker, Solver};
+
+pub mod cli;
+pub mod complexity;
+pub mod history;
+
+/// A contest's directory in the workspace and the problems in it.
+pub struct Contest {
+    /// Name of the contest's directory, e.g. `september-2025`.
+    pub name: &'static str,
+    /// Every problem in the contest.
+    pub problems: &'static [&'static dyn Solver],
+    /// The random input generator of every problem, in the same order as `problems`.
+    pub generators: &'static [&'static dyn Generator],
+    /// The failing-input shrinker of every problem, in the same order as `problems`.
+    pub shrinkers: &'static [&'static dyn Shrinker],
+}
+
+macro_rules! contests {
+    ($($contest:literal => [$($problem:ident),*$(,)?]),*$(,)?) => {
+        /// Every contest with problems in the workspace, in the order the workspace lists them.
+        pub static CONTESTS: &[Contest] = &[$(Contest {
+            name: $contest,
+            problems: &[$(&$problem::Solution),+],
+            generators: &[$(&$problem::Solution),+],
+            shrinkers: &[$(&$problem::Solution),+],
+        }),*];
+    };
+}
+
+impl Contest {
+    /// Look up a problem by its letter, ignoring case.
+    pub fn find(&self, letter: char) -> Option<&'static dyn Solver> {
+        self.problems
+            .iter()
+            .copied()
+            .find(|problem| problem.letter().eq_ignore_ascii_case(&letter))
+    }
+
+    /// Look up every problem in `letters`, or every problem at all if it is empty, failing with
+    /// the first letter that names no problem.
+    pub fn select(&self, letters: &[char]) -> Result<Vec<&'static dyn Solver>, char> {
+        if letters.is_empty() {
+            return Ok(self.problems.to_vec());
+        }
+        letters
+            .iter()
+            .map(|&letter| self.find(letter).ok_or(letter))
+            .collect()
+    }
+
+    /// Look up a problem's input generator by its letter, ignoring case.
+    pub fn find_generator(&self, letter: char) -> Option<&'static dyn Generator> {
+        self.generators
+            .iter()
+            .copied()
+            .find(|problem| problem.letter().eq_ignore_ascii_case(&letter))
+    }
+
+    /// Look up a problem's failing-input shrinker by its letter, ignoring case.
+    pub fn find_shrinker(&self, letter: char) -> Option<&'static dyn Shrinker> {
+        self.shrinkers
+            .iter()
+            .copied()
+            .find(|problem| problem.letter().eq_ignore_ascii_case(&letter))
+    }
+
+    /// The contest's directory, holding its problem crates.
+    pub fn dir(&self) -> PathBuf {
+        workspace_dir().join(self.name)
+    }
+
+    /// The crate directory of a problem, where its `output.txt` lives.
+    pub fn problem_dir(&self, letter: char) -> PathBuf {
+        self.dir()
+            .join(format!("problem-{}", letter.to_ascii_lowercase()))
+    }
+
+    /// Where a problem's minimized failing inputs are kept, each of which must no longer fail.
+    pub fn regressions_dir(&self, letter: char) -> PathBuf {
+        self.problem_dir(letter).join("regressions")
+    }
+}
+
+/// Look up a contest by name, or pick the one the current directory is in, falling back to the
+/// latest contest if it is in none.
+pub fn contest(name: Option<&str>) -> Result<&'static Contest, String> {
+    if let Some(name) = name {
+        return CONTESTS
+            .iter()
+            .find(|contest| contest.name == name)
+            .ok_or_else(|| format!("no such contest: {name:?}"));
+    }
+    let cwd = std::env::current_dir().ok();
+    let cwd = cwd.as_deref().and_then(|cwd| cwd.canonicalize().ok());
+    let current = cwd.and_then(|cwd| {
+        CONTESTS
+            .iter()
+            .find(|contest| cwd.starts_with(contest.dir()))
+    });
+    current
+        .or(CONTESTS.last())
+        .ok_or_else(|| "the workspace has no contests".to_owned())
+}
+
+/// The root of the workspace, i.e. the parent of this crate.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+// Every `CONTEST/problem-*` member of the workspace; see `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/problems.rs"));
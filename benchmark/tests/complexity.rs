@@ -0,0 +1,48 @@
+use benchmark::complexity::{Class, exponent, fit, geometric};
+
+fn points(f: impl Fn(f64) -> f64) -> Vec<(f64, f64)> {
+    geometric(4, 256, 2.0)
+        .into_iter()
+        .map(|n| (n as f64, 1e-9 * f(n as f64)))
+        .collect()
+}
+
+#[test]
+fn sweeps_grow_geometrically() {
+    assert_eq!(geometric(1, 20, 2.0), [1, 2, 4, 8, 16]);
+    assert_eq!(geometric(2, 10, 1.5), [2, 3, 5, 7, 10]);
+}
+
+#[test]
+fn exact_measurements_fit_their_own_class() {
+    for class in Class::ALL {
+        if class == Class::Exponential {
+            continue;
+        }
+        let best = fit(&points(|n| class.eval(n)))[0];
+        assert_eq!(best.class, class);
+        assert!(best.error < 1e-9);
+    }
+}
+
+#[test]
+fn exponent_of_a_power_law() {
+    let found = exponent(&points(|n| n.powf(1.5))).unwrap();
+    assert!((found - 1.5).abs() < 1e-9, "{found}");
+}
+
+#[test]
+fn exponential_growth_is_recognized() {
+    let points: Vec<(f64, f64)> = (1..=20)
+        .map(|n| (n as f64, 3e-9 * (n as f64).exp2()))
+        .collect();
+    assert_eq!(fit(&points)[0].class, Class::Exponential);
+}
+
+#[test]
+fn constant_overhead_is_separated_out() {
+    let best = fit(&points(|n| 5000.0 + n * n))[0];
+    assert_eq!(best.class, Class::Quadratic);
+    assert!((best.overhead - 5e-6).abs() < 1e-9);
+    assert!((best.predict(100.0) - 1.5e-5).abs() < 1e-9);
+}
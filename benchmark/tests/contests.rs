@@ -0,0 +1,22 @@
+//! The registry built from the workspace's contests.
+
+#[test]
+fn contests_have_distinct_letters() {
+    assert!(!benchmark::CONTESTS.is_empty());
+    for contest in benchmark::CONTESTS {
+        let mut letters: Vec<char> = contest.problems.iter().map(|p| p.letter()).collect();
+        letters.sort();
+        letters.dedup();
+        assert_eq!(letters.len(), contest.problems.len(), "{}", contest.name);
+        assert!(contest.dir().is_dir(), "{}", contest.name);
+    }
+}
+
+#[test]
+fn contest_by_name() {
+    let contest = benchmark::contest(Some("september-2025")).unwrap();
+    assert_eq!(contest.name, "september-2025");
+    assert!(contest.find('A').is_some());
+    assert!(contest.problem_dir('a').join("output.txt").is_file());
+    assert!(benchmark::contest(Some("no-such-contest")).is_err());
+}
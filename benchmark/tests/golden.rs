@@ -0,0 +1,56 @@
+//! Every problem's answers to its own input must check out against its committed `output.txt`.
+
+use benchmark::{CONTESTS, Contest};
+use common::Solver;
+
+/// Why a problem's answers don't check out, if they don't.
+fn mismatch(contest: &Contest, problem: &dyn Solver, slow: bool) -> Option<String> {
+    let path = contest.problem_dir(problem.letter()).join("output.txt");
+    let expected = std::fs::read_to_string(&path).unwrap();
+    // A problem `cargo tasks start-solve` has only just scaffolded.
+    if problem.input().trim().is_empty() {
+        return Some(format!(
+            "{} problem {}'s src/input.txt is empty",
+            contest.name,
+            problem.letter()
+        ));
+    }
+    if expected.trim().is_empty() {
+        return Some(format!(
+            "{} is empty; fill it with `cargo tasks save-output`",
+            path.display()
+        ));
+    }
+    let report = problem
+        .check_golden(&expected, slow)
+        .expect("invalid input");
+    (!report.is_accepted()).then(|| format!("{} does not match:\n{report}", path.display()))
+}
+
+/// Check every problem at once, since some take a while.
+fn check_every_problem(slow: bool) {
+    let mismatches: Vec<String> = std::thread::scope(|scope| {
+        let threads: Vec<_> = CONTESTS
+            .iter()
+            .flat_map(|contest| contest.problems.iter().map(move |&p| (contest, p)))
+            .map(|(contest, problem)| scope.spawn(move || mismatch(contest, problem, slow)))
+            .collect();
+        threads
+            .into_iter()
+            .filter_map(|thread| thread.join().unwrap())
+            .collect()
+    });
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n\n"));
+}
+
+/// Leaves out the cases each problem's `slow_case` flags.
+#[test]
+fn golden() {
+    check_every_problem(false);
+}
+
+#[test]
+#[ignore = "takes minutes; run with `--include-ignored`"]
+fn full_golden() {
+    check_every_problem(true);
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use benchmark::history::{History, Record, regressions};
+
+fn record(commit: &str, problem: char, millis: u64) -> Record {
+    Record {
+        commit: commit.to_owned(),
+        problem,
+        median: Duration::from_millis(millis),
+        runs: 5,
+    }
+}
+
+#[test]
+fn history_round_trips_through_csv() {
+    let mut history = History::default();
+    history.record(record("abc", 'a', 10));
+    history.record(record("abc", 'b', 20));
+    history.record(record("def-dirty", 'a', 30));
+    assert_eq!(History::parse(&history.to_csv()).unwrap(), history);
+}
+
+#[test]
+fn recording_again_replaces_the_old_record() {
+    let mut history = History::default();
+    history.record(record("abc", 'a', 10));
+    history.record(record("def", 'a', 20));
+    history.record(record("abc", 'a', 30));
+    assert_eq!(
+        history.at("abc").collect::<Vec<_>>(),
+        [&record("abc", 'a', 30)]
+    );
+    assert_eq!(history.latest_before("abc"), Some("def"));
+}
+
+#[test]
+fn only_slowdowns_beyond_the_threshold_regress() {
+    let baseline = [record("abc", 'a', 100), record("abc", 'b', 100)];
+    let baseline: Vec<&Record> = baseline.iter().collect();
+    let current = [
+        record("def", 'a', 105),
+        record("def", 'b', 150),
+        record("def", 'c', 1000),
+    ];
+
+    let found = regressions(&baseline, &current, 0.1);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].problem, 'b');
+    assert!((found[0].slowdown() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn malformed_history_is_rejected() {
+    let err = History::parse("commit,problem,median_nanoseconds,runs\nabc,a,fast,5\n").unwrap_err();
+    assert_eq!(err.to_string(), "line 2: invalid median");
+}
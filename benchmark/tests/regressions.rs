@@ -0,0 +1,19 @@
+//! Every input `stress` has saved must no longer make its problem fail.
+
+#[test]
+fn regressions() {
+    for contest in benchmark::CONTESTS {
+        for problem in contest.shrinkers {
+            let Ok(entries) = std::fs::read_dir(contest.regressions_dir(problem.letter())) else {
+                continue;
+            };
+            for entry in entries {
+                let path = entry.unwrap().path();
+                let input = std::fs::read_to_string(&path).unwrap();
+                if let Some(failure) = problem.failure_of(&input) {
+                    panic!("{}: problem {} {failure}", path.display(), problem.letter());
+                }
+            }
+        }
+    }
+}
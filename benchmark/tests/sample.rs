@@ -0,0 +1,33 @@
+//! Every problem's answers to the sample in its statement must check out against the sample
+//! output, for the problems whose statement has one.
+
+use benchmark::CONTESTS;
+
+#[test]
+fn samples() {
+    for contest in CONTESTS {
+        for problem in contest.problems {
+            let src = contest.problem_dir(problem.letter()).join("src");
+            let Ok(expected) = std::fs::read_to_string(src.join("sample_output.txt")) else {
+                continue;
+            };
+            let input = std::fs::read_to_string(src.join("sample_input.txt")).unwrap();
+            assert!(
+                !input.trim().is_empty() && !expected.trim().is_empty(),
+                "{} problem {} has no sample yet; paste the statement's into its src/",
+                contest.name,
+                problem.letter()
+            );
+            let found = problem.solve_str(&input).expect("invalid input");
+            let report = problem
+                .check_output(&input, &expected, &found)
+                .expect("invalid input");
+            assert!(
+                report.is_accepted(),
+                "{} problem {} does not match its sample_output.txt:\n{report}",
+                contest.name,
+                problem.letter()
+            );
+        }
+    }
+}
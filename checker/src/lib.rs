@@ -0,0 +1,245 @@
+//! Compare a produced output against the expected one, case by case.
+
+use std::fmt;
+
+/// How the answer to a single case is compared against the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Comparison {
+    /// Lines must match exactly, ignoring only trailing whitespace.
+    Exact,
+
+    /// Whitespace-separated tokens must match exactly.
+    #[default]
+    Tokens,
+
+    /// Like [`Comparison::Tokens`], but tokens that are both numbers only need to be within
+    /// `absolute` or within `relative` times the expected value of each other.
+    Float { absolute: f64, relative: f64 },
+}
+
+/// Where two answers first differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// 1-based index of a whitespace-separated token.
+    Token(usize),
+
+    /// 1-based line number within the case.
+    Line(usize),
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Token(n) => write!(f, "token {n}"),
+            Self::Line(n) => write!(f, "line {n}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Accepted,
+
+    /// The answers first differ at `at`; `None` means that side ran out first.
+    WrongAnswer {
+        at: Position,
+        expected: Option<String>,
+        found: Option<String>,
+    },
+
+    /// The expected output has this case but the produced one does not.
+    MissingCase,
+
+    /// The produced output has this case but the expected one does not.
+    UnexpectedCase,
+
+    /// A special judge found the answer invalid or suboptimal.
+    Rejected(String),
+}
+
+impl Verdict {
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = |side: &Option<String>| match side {
+            Some(token) => format!("{token:?}"),
+            None => "nothing".to_owned(),
+        };
+
+        match self {
+            Self::Accepted => write!(f, "AC"),
+            Self::WrongAnswer {
+                at,
+                expected,
+                found,
+            } => write!(
+                f,
+                "WA ({at}: expected {}, found {})",
+                side(expected),
+                side(found)
+            ),
+            Self::MissingCase => write!(f, "WA (missing from output)"),
+            Self::UnexpectedCase => write!(f, "WA (not in expected output)"),
+            Self::Rejected(reason) => write!(f, "WA ({reason})"),
+        }
+    }
+}
+
+/// The verdict for every case of an output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Verdicts keyed by case number, in order. Case 0 holds any text before `Case #1:`.
+    pub cases: Vec<(usize, Verdict)>,
+}
+
+impl Report {
+    pub fn is_accepted(&self) -> bool {
+        self.cases.iter().all(|(_, verdict)| verdict.is_accepted())
+    }
+
+    pub fn accepted(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|(_, verdict)| verdict.is_accepted())
+            .count()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (case, verdict) in &self.cases {
+            writeln!(f, "Case #{case}: {verdict}")?;
+        }
+        write!(f, "{}/{} accepted", self.accepted(), self.cases.len())
+    }
+}
+
+/// Parse a `Case #N:` header at the start of `line`, returning `N` and the rest of the line.
+fn case_header(line: &str) -> Option<(usize, &str)> {
+    let rest = line.strip_prefix("Case #")?;
+    let (number, rest) = rest.split_once(':')?;
+    Some((number.parse().ok()?, rest))
+}
+
+/// Split an output into the answers to each of its cases, keyed by case number.
+///
+/// Text before the first `Case #N:` header, if any, is returned as case 0.
+pub fn split_cases(output: &str) -> Vec<(usize, &str)> {
+    let mut cases = Vec::new();
+    let mut current = 0;
+    let mut start = 0;
+
+    let mut offset = 0;
+    for line in output.split_inclusive('\n') {
+        if let Some((number, rest)) = case_header(line) {
+            let body = &output[start..offset];
+            if current != 0 || !body.trim().is_empty() {
+                cases.push((current, body));
+            }
+            current = number;
+            start = offset + line.len() - rest.len();
+        }
+        offset += line.len();
+    }
+
+    let body = &output[start..];
+    if current != 0 || !body.trim().is_empty() {
+        cases.push((current, body));
+    }
+    cases
+}
+
+fn tokens_match(comparison: Comparison, expected: &str, found: &str) -> bool {
+    if expected == found {
+        return true;
+    }
+
+    let Comparison::Float { absolute, relative } = comparison else {
+        return false;
+    };
+    let (Ok(expected), Ok(found)) = (expected.parse::<f64>(), found.parse::<f64>()) else {
+        return false;
+    };
+    let difference = (expected - found).abs();
+    difference <= absolute || difference <= relative * expected.abs()
+}
+
+/// Compare the answer to a single case against the expected one.
+pub fn check_case(comparison: Comparison, expected: &str, found: &str) -> Verdict {
+    let (expected, found): (Vec<&str>, Vec<&str>) = match comparison {
+        Comparison::Exact => (
+            expected.trim().lines().map(str::trim_end).collect(),
+            found.trim().lines().map(str::trim_end).collect(),
+        ),
+        Comparison::Tokens | Comparison::Float { .. } => (
+            expected.split_whitespace().collect(),
+            found.split_whitespace().collect(),
+        ),
+    };
+
+    let matches = |expected: &str, found: &str| match comparison {
+        Comparison::Exact => expected == found,
+        _ => tokens_match(comparison, expected, found),
+    };
+
+    let Some(i) =
+        (0..expected.len().max(found.len())).find(|&i| match (expected.get(i), found.get(i)) {
+            (Some(expected), Some(found)) => !matches(expected, found),
+            _ => true,
+        })
+    else {
+        return Verdict::Accepted;
+    };
+
+    Verdict::WrongAnswer {
+        at: match comparison {
+            Comparison::Exact => Position::Line(i + 1),
+            _ => Position::Token(i + 1),
+        },
+        expected: expected.get(i).map(|&s| s.to_owned()),
+        found: found.get(i).map(|&s| s.to_owned()),
+    }
+}
+
+fn find_case<'a>(cases: &[(usize, &'a str)], n: usize) -> Option<&'a str> {
+    cases.iter().find(|&&(m, _)| m == n).map(|&(_, body)| body)
+}
+
+/// Compare a whole output against the expected one, case by case.
+pub fn check(comparison: Comparison, expected: &str, found: &str) -> Report {
+    check_with(expected, found, |_, expected, found| match expected {
+        Some(expected) => check_case(comparison, expected, found),
+        None => Verdict::UnexpectedCase,
+    })
+}
+
+/// Like [`check`], but judging every produced answer with `judge`, which is given the case number
+/// along with the expected answer, if there is one, and the produced one.
+pub fn check_with<F>(expected: &str, found: &str, mut judge: F) -> Report
+where
+    F: FnMut(usize, Option<&str>, &str) -> Verdict,
+{
+    let expected = split_cases(expected);
+    let found = split_cases(found);
+
+    let mut numbers: Vec<usize> = expected.iter().chain(&found).map(|&(n, _)| n).collect();
+    numbers.sort_unstable();
+    numbers.dedup();
+
+    let cases = numbers
+        .into_iter()
+        .map(|n| {
+            let verdict = match (find_case(&expected, n), find_case(&found, n)) {
+                (expected, Some(found)) => judge(n, expected, found),
+                (_, None) => Verdict::MissingCase,
+            };
+            (n, verdict)
+        })
+        .collect();
+
+    Report { cases }
+}
@@ -0,0 +1,125 @@
+//! Budgets that cut long searches short, settling for the best answer found so far.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// How much a search may do on a single case before giving up. Unlimited by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Wall time per case.
+    pub time: Option<Duration>,
+    /// Search nodes visited per case.
+    pub nodes: Option<u64>,
+}
+
+/// [`Budget::time`] of every search from now on in nanoseconds, or 0 for none.
+static TIME: AtomicU64 = AtomicU64::new(0);
+
+/// [`Budget::nodes`] of every search from now on, or `u64::MAX` for none.
+static NODES: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// Set by [`cancel_searches`].
+static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Give every search started from now on `budget`.
+pub fn set_budget(budget: Budget) {
+    let time = budget.time.map_or(0, |time| {
+        u64::try_from(time.as_nanos()).unwrap_or(u64::MAX).max(1)
+    });
+    TIME.store(time, Ordering::Relaxed);
+    NODES.store(budget.nodes.unwrap_or(u64::MAX), Ordering::Relaxed);
+}
+
+/// The budget set with [`set_budget`].
+pub fn budget() -> Budget {
+    let time = TIME.load(Ordering::Relaxed);
+    let nodes = NODES.load(Ordering::Relaxed);
+    Budget {
+        time: (time != 0).then(|| Duration::from_nanos(time)),
+        nodes: (nodes != u64::MAX).then_some(nodes),
+    }
+}
+
+/// Make every search, running or not yet started, give up within a few thousand nodes.
+pub fn cancel_searches() {
+    CANCELLED.store(true, Ordering::Relaxed);
+}
+
+/// Undo [`cancel_searches`].
+pub fn resume_searches() {
+    CANCELLED.store(false, Ordering::Relaxed);
+}
+
+/// What is left of a [`Budget`] while a search runs; [`Countdown::tick`] it once per node.
+#[derive(Debug, Clone)]
+pub struct Countdown {
+    deadline: Option<Instant>,
+    nodes: u64,
+    visited: u64,
+    exhausted: bool,
+}
+
+impl Countdown {
+    /// How often, in nodes, the clock is read, since reading it on every node would be slow.
+    const CLOCK_EVERY: u64 = 1 << 12;
+
+    /// Start counting down `budget`.
+    pub fn new(budget: Budget) -> Self {
+        Self {
+            deadline: budget.time.map(|time| Instant::now() + time),
+            nodes: budget.nodes.unwrap_or(u64::MAX),
+            visited: 0,
+            exhausted: false,
+        }
+    }
+
+    /// Start counting down the budget set with [`set_budget`].
+    pub fn start() -> Self {
+        Self::new(budget())
+    }
+
+    /// Count one more node, returning whether the search may go on.
+    #[inline]
+    pub fn tick(&mut self) -> bool {
+        if self.exhausted {
+            return false;
+        }
+        self.visited += 1;
+        if self.visited > self.nodes {
+            self.exhausted = true;
+        } else if self.visited.is_multiple_of(Self::CLOCK_EVERY) {
+            self.exhausted = CANCELLED.load(Ordering::Relaxed)
+                || self
+                    .deadline
+                    .is_some_and(|deadline| Instant::now() >= deadline);
+        }
+        !self.exhausted
+    }
+
+    /// Whether the budget ran out, in which case the search was cut short.
+    pub fn exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Number of nodes counted so far.
+    pub fn visited(&self) -> u64 {
+        self.visited
+    }
+
+    /// Wrap up a search, marking `best` as optimal if the budget never ran out.
+    pub fn finish<T>(&self, best: T) -> Searched<T> {
+        Searched {
+            best,
+            optimal: !self.exhausted,
+        }
+    }
+}
+
+/// The answer of a search that may have been cut short by its [`Budget`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Searched<T> {
+    /// The best answer found.
+    pub best: T,
+    /// Whether the search ran to completion, proving `best` optimal.
+    pub optimal: bool,
+}
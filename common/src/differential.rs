@@ -0,0 +1,83 @@
+//! Differential testing of a problem's solver against a slow but obviously correct one.
+
+use std::fmt;
+
+use crate::{Problem, Verdict};
+
+/// A problem with a brute-force reference solver to test its real one against.
+pub trait Reference: Problem {
+    /// Solve a single case the slow way. Only ever called on small inputs.
+    fn reference_case(input: &Self::Input, case: &Self::Case) -> Self::Output;
+}
+
+/// An input on which the solver and the reference solver disagree.
+#[derive(Debug)]
+pub struct Mismatch {
+    pub input: String,
+    /// 1-based number of the first case they disagree on.
+    pub case: usize,
+    pub expected: String,
+    pub found: String,
+    /// The solver's answer judged with the reference's as the expected one.
+    pub verdict: Verdict,
+    /// The reference's answer judged with the solver's as the expected one.
+    pub reference_verdict: Verdict,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "case #{} of input:", self.case)?;
+        writeln!(f, "{}", self.input.trim_end())?;
+        writeln!(f, "reference answer:")?;
+        writeln!(f, "{}", self.expected.trim_end())?;
+        writeln!(f, "solver answer:")?;
+        writeln!(f, "{}", self.found.trim_end())?;
+        writeln!(f, "verdict: {}", self.verdict)?;
+        write!(f, "reference verdict: {}", self.reference_verdict)
+    }
+}
+
+/// Solve every input with both solvers and return the first case they disagree on, judging both
+/// ways. Panics if an input does not parse.
+pub fn differential<P, I>(inputs: I) -> Result<usize, Box<Mismatch>>
+where
+    P: Reference,
+    I: IntoIterator<Item = String>,
+{
+    let mut count = 0;
+    for input in inputs {
+        let parsed = P::parse(&input).unwrap_or_else(|err| panic!("invalid input: {err}\n{input}"));
+        for (i, case) in parsed.as_ref().iter().enumerate() {
+            let expected = P::format_case(&P::reference_case(&parsed, case));
+            let found = P::format_case(&P::solve_case(&parsed, case));
+            let verdict = P::judge_case(&parsed, case, Some(&expected), &found);
+            let reference_verdict = P::judge_case(&parsed, case, Some(&found), &expected);
+            if !verdict.is_accepted() || !reference_verdict.is_accepted() {
+                return Err(Box::new(Mismatch {
+                    input,
+                    case: i + 1,
+                    expected,
+                    found,
+                    verdict,
+                    reference_verdict,
+                }));
+            }
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Like [`differential`], but panicking on the first mismatch.
+pub fn assert_differential<P, I>(inputs: I)
+where
+    P: Reference,
+    I: IntoIterator<Item = String>,
+{
+    if let Err(mismatch) = differential::<P, I>(inputs) {
+        panic!(
+            "problem {} disagrees with its reference on {mismatch}",
+            P::LETTER
+        );
+    }
+}
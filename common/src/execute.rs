@@ -0,0 +1,63 @@
+//! Solving the cases of an input, in parallel or one after the other.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use rayon::prelude::*;
+
+use crate::{Allocations, Progress, count_allocations, counting_allocations, timed};
+
+/// Number of threads to solve cases on, or 0 for rayon's default of one per core.
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Solve cases on `threads` threads; only takes effect before the first parallel solve.
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+    if threads > 1 {
+        // Fails if the pool is already running, in which case it keeps its size.
+        let _ = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global();
+    }
+}
+
+/// Whether cases may be solved in parallel at all.
+pub fn parallelism_enabled() -> bool {
+    !cfg!(feature = "sequential") && !counting_allocations() && THREADS.load(Ordering::Relaxed) != 1
+}
+
+/// What solving a single case took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cost {
+    pub time: Duration,
+    /// Only known while allocations are being counted; see [`count_allocations`].
+    pub allocations: Option<Allocations>,
+}
+
+/// Run `f`, returning its result along with what it cost.
+pub fn measured<T>(f: impl FnOnce() -> T) -> (T, Cost) {
+    let ((result, allocations), time) = timed(|| count_allocations(f));
+    (result, Cost { time, allocations })
+}
+
+/// Solve and measure every case, in parallel if `parallel` is set, returning answers in order.
+pub fn solve_cases<C, O, F>(cases: &[C], parallel: bool, solve: F) -> Vec<(O, Cost)>
+where
+    C: Sync,
+    O: Send,
+    F: Fn(&C) -> O + Sync,
+{
+    let progress = Progress::new(cases.len());
+    let solve = |case| {
+        let solved = measured(|| solve(case));
+        progress.inc();
+        solved
+    };
+    let solved = if parallel && parallelism_enabled() {
+        cases.par_iter().map(solve).collect()
+    } else {
+        cases.iter().map(solve).collect()
+    };
+    progress.finish();
+    solved
+}
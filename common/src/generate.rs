@@ -0,0 +1,121 @@
+//! Seeded random input generators.
+
+use std::fmt::Write;
+
+use rand::SeedableRng;
+
+use crate::{Problem, Solver};
+
+/// The random number generator inputs are generated with; seeded, so inputs are reproducible.
+pub type Rng = rand_chacha::ChaCha8Rng;
+
+pub fn rng(seed: u64) -> Rng {
+    Rng::seed_from_u64(seed)
+}
+
+/// Limits on generated inputs, settable by name from the command line; see [`constraints!`].
+pub trait Constraints: Default {
+    /// Set the constraint called `key` from its textual `value`.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    /// Every constraint along with its current value.
+    fn describe(&self) -> Vec<(&'static str, String)>;
+}
+
+/// Declare a [`Constraints`] struct, giving each field its default value.
+#[macro_export]
+macro_rules! constraints {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $ty),*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self { $($field: $default),* }
+            }
+        }
+
+        impl $crate::Constraints for $name {
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(stringify!($field) => {
+                        self.$field = value.parse().map_err(|err| format!("{key}: {err}"))?;
+                    })*
+                    _ => {
+                        return Err(format!(
+                            "unknown constraint {key:?}, expected one of: {}",
+                            [$(stringify!($field)),*].join(", ")
+                        ));
+                    }
+                }
+                Ok(())
+            }
+
+            fn describe(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), self.$field.to_string())),*]
+            }
+        }
+    };
+}
+
+/// A problem that can generate random valid inputs for itself.
+pub trait Generate: Problem {
+    type Constraints: Constraints;
+
+    /// Generate a whole input file.
+    fn generate(rng: &mut Rng, constraints: &Self::Constraints) -> String;
+}
+
+/// Object-safe view of a [`Generate`] problem.
+pub trait Generator: Solver {
+    /// Every constraint along with its default value.
+    fn default_constraints(&self) -> Vec<(&'static str, String)>;
+
+    /// Generate an input from `seed`, overriding the given constraints.
+    fn generate_input(&self, seed: u64, constraints: &[(&str, &str)]) -> Result<String, String>;
+}
+
+impl<P: Generate + Sync> Generator for P {
+    fn default_constraints(&self) -> Vec<(&'static str, String)> {
+        P::Constraints::default().describe()
+    }
+
+    fn generate_input(&self, seed: u64, overrides: &[(&str, &str)]) -> Result<String, String> {
+        let mut constraints = P::Constraints::default();
+        for (key, value) in overrides {
+            constraints.set(key, value)?;
+        }
+        Ok(P::generate(&mut rng(seed), &constraints))
+    }
+}
+
+/// Join cases into an input that starts with how many there are.
+pub fn counted_input<I>(cases: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let cases: Vec<_> = cases.into_iter().collect();
+    let mut input = cases.len().to_string();
+    for case in cases {
+        write!(input, "\n{}", case.as_ref()).unwrap();
+    }
+    input
+}
+
+/// A random string of `len` letters drawn from `alphabet`.
+pub fn random_word(rng: &mut Rng, alphabet: &[u8], len: usize) -> String {
+    use rand::seq::IndexedRandom;
+
+    (0..len)
+        .map(|_| *alphabet.choose(rng).unwrap() as char)
+        .collect()
+}
@@ -0,0 +1,137 @@
+use std::{
+    borrow::Cow,
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    process,
+    time::Duration,
+};
+
+use crate::{Budget, Solver, set_budget, set_threads, set_verbosity, show_progress};
+
+/// Where a binary should read its input from, as chosen on its command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The `input.txt` compiled into the binary.
+    Embedded,
+    Stdin,
+    Path(String),
+}
+
+impl InputSource {
+    /// `-` is stdin, anything else a path, and no argument the embedded input.
+    pub fn from_arg(arg: Option<String>) -> Self {
+        match arg.as_deref() {
+            None => Self::Embedded,
+            Some("-") => Self::Stdin,
+            Some(path) => Self::Path(path.to_owned()),
+        }
+    }
+
+    pub fn read(self, embedded: &str) -> io::Result<Cow<'_, str>> {
+        match self {
+            Self::Embedded => Ok(Cow::Borrowed(embedded)),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Owned(input))
+            }
+            Self::Path(path) => fs::read_to_string(path).map(Cow::Owned),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Embedded => write!(f, "<embedded input>"),
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Path(path) => write!(f, "{path}"),
+        }
+    }
+}
+
+/// Entry point of every problem binary: solve the input named on the command line and print the
+/// answer.
+pub fn main(problem: &dyn Solver) {
+    let mut args = env::args();
+    let program = args.next().unwrap_or_default();
+    let usage = || -> ! {
+        eprintln!(
+            "usage: {program} [-v | -vv] [--threads N] [--time-limit SECONDS] [--node-limit N] \
+             [INPUT | -]"
+        );
+        process::exit(2);
+    };
+
+    let mut source = None;
+    let mut budget = Budget::default();
+    let mut verbosity = 0;
+    while let Some(arg) = args.next() {
+        if let Some(vs) = arg
+            .strip_prefix('-')
+            .filter(|vs| !vs.is_empty() && vs.bytes().all(|v| v == b'v'))
+        {
+            verbosity += vs.len();
+        } else if arg == "--threads" {
+            match args.next().and_then(|threads| threads.parse().ok()) {
+                Some(threads) => set_threads(threads),
+                None => usage(),
+            }
+        } else if arg == "--time-limit" {
+            match args
+                .next()
+                .and_then(|secs| Duration::try_from_secs_f64(secs.parse().ok()?).ok())
+            {
+                Some(time) => budget.time = Some(time),
+                None => usage(),
+            }
+        } else if arg == "--node-limit" {
+            match args.next().and_then(|nodes| nodes.parse().ok()) {
+                Some(nodes) => budget.nodes = Some(nodes),
+                None => usage(),
+            }
+        } else if source.is_none() {
+            source = Some(arg);
+        } else {
+            usage();
+        }
+    }
+    set_budget(budget);
+    set_verbosity(verbosity.try_into().unwrap_or(u8::MAX));
+    show_progress(true);
+    let source = InputSource::from_arg(source);
+
+    let name = source.to_string();
+    let input = source.read(problem.input()).unwrap_or_else(|err| {
+        eprintln!("error: {name}: {err}");
+        process::exit(1);
+    });
+
+    match problem.run_timed(&input) {
+        Ok(run) => {
+            println!("{}", run.output);
+            if let Some(allocations) = run.allocations {
+                eprintln!("{name}: {allocations}");
+            }
+            if !run.timed_out.is_empty() {
+                let cases: Vec<String> = run
+                    .timed_out
+                    .iter()
+                    .map(|case| format!("#{case}"))
+                    .collect();
+                let plural = if cases.len() == 1 { "" } else { "s" };
+                eprintln!(
+                    "warning: {name}: ran out of budget on case{plural} {}, so the answer{plural} \
+                     may not be optimal",
+                    cases.join(", ")
+                );
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {name}: {err}");
+            process::exit(1);
+        }
+    }
+}
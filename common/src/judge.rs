@@ -0,0 +1,38 @@
+//! Helpers for special judges, see [`Problem::judge_case`](crate::Problem::judge_case).
+
+use crate::{ScanError, Scanner, Verdict};
+
+/// Reject an answer with the given reason.
+pub fn reject(reason: impl Into<String>) -> Verdict {
+    Verdict::Rejected(reason.into())
+}
+
+/// Turn the result of a judge into a verdict, accepting the answer if nothing was wrong with it.
+pub fn verdict(result: Result<(), Verdict>) -> Verdict {
+    result.err().unwrap_or(Verdict::Accepted)
+}
+
+/// Parse an answer with `f`, rejecting it if it is malformed or has anything left over.
+pub fn parse_answer<'a, T, F>(answer: &'a str, f: F) -> Result<T, Verdict>
+where
+    F: FnOnce(&mut Scanner<'a>) -> Result<T, ScanError>,
+{
+    let mut scanner = Scanner::new(answer);
+    let value = f(&mut scanner).map_err(|err| reject(format!("malformed answer: {err}")))?;
+    if !scanner.is_empty() {
+        return Err(reject("unexpected trailing output"));
+    }
+    Ok(value)
+}
+
+/// Reject `found` unless it is as good as `expected`, naming the quantity as `what`.
+pub fn check_optimal<T>(what: &str, expected: T, found: T) -> Result<(), Verdict>
+where
+    T: PartialEq + std::fmt::Display,
+{
+    if expected == found {
+        Ok(())
+    } else {
+        Err(reject(format!("{what} is {found}, expected {expected}")))
+    }
+}
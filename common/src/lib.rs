@@ -0,0 +1,52 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+mod allocations;
+mod budget;
+mod differential;
+mod execute;
+mod generate;
+mod input;
+mod judge;
+mod limit;
+mod logging;
+mod problem;
+mod progress;
+mod scanner;
+mod shrink;
+
+pub use allocations::*;
+pub use budget::*;
+pub use checker::{Comparison, Report, Verdict};
+pub use differential::*;
+pub use execute::*;
+pub use generate::*;
+pub use input::*;
+pub use judge::*;
+pub use limit::*;
+pub use log;
+pub use logging::*;
+pub use problem::*;
+pub use progress::*;
+pub use rand;
+pub use scanner::*;
+pub use shrink::*;
+
+pub fn cases<I>(it: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    it.into_iter()
+        .enumerate()
+        .map(|(i, s)| format!("Case #{}: {}", i + 1, s))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Run `f`, returning its result along with how long it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
@@ -0,0 +1,120 @@
+//! Limits on inputs that solvers rely on, checked before solving.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::ScanError;
+
+/// A bound on some quantity in the input that a solver relies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limit {
+    /// What is being bounded, e.g. `"grid width"`.
+    pub what: &'static str,
+    pub min: u64,
+    pub max: u64,
+    /// Why the solver needs the bound, e.g. `"rows are u64 bitmasks"`.
+    pub reason: &'static str,
+}
+
+impl Limit {
+    /// Check that `value` is within the limit.
+    pub fn check(&'static self, value: impl TryInto<u64>) -> Result<(), LimitError> {
+        self.check_at_most(value, self.max)
+    }
+
+    /// Like [`Limit::check`], but with a tighter maximum that depends on the rest of the case.
+    pub fn check_at_most(
+        &'static self,
+        value: impl TryInto<u64>,
+        max: u64,
+    ) -> Result<(), LimitError> {
+        let found = value.try_into().unwrap_or(u64::MAX);
+        let max = max.min(self.max);
+        if (self.min..=max).contains(&found) {
+            Ok(())
+        } else {
+            Err(LimitError {
+                case: None,
+                limit: self,
+                found,
+                max,
+            })
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} in {}..={} ({})",
+            self.what, self.min, self.max, self.reason
+        )
+    }
+}
+
+/// An input that parsed fine but is outside what the solver supports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitError {
+    /// The case the limit is exceeded in.
+    pub case: Option<usize>,
+    pub limit: &'static Limit,
+    pub found: u64,
+    /// The maximum that applied, which may be tighter than `limit.max`.
+    pub max: u64,
+}
+
+impl LimitError {
+    /// Attribute the error to the given 1-based case.
+    pub fn in_case(self, case: usize) -> Self {
+        Self {
+            case: Some(case),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(case) = self.case {
+            write!(f, "case #{case}: ")?;
+        }
+        write!(
+            f,
+            "{} is {}, but only {}..={} is supported ({})",
+            self.limit.what, self.found, self.limit.min, self.max, self.limit.reason
+        )
+    }
+}
+
+impl Error for LimitError {}
+
+/// Why an input can't be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    Malformed(ScanError),
+    Unsupported(LimitError),
+}
+
+impl From<ScanError> for InputError {
+    fn from(err: ScanError) -> Self {
+        Self::Malformed(err)
+    }
+}
+
+impl From<LimitError> for InputError {
+    fn from(err: LimitError) -> Self {
+        Self::Unsupported(err)
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(err) => err.fmt(f),
+            Self::Unsupported(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for InputError {}
@@ -0,0 +1,46 @@
+//! Diagnostics from solvers through the [`log`] macros, written to stderr with `-v`.
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+struct Stderr;
+
+impl Log for Stderr {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{:<5} {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static STDERR: Stderr = Stderr;
+
+/// Log per-case summaries to stderr at verbosity 1 and search traces too from 2.
+pub fn set_verbosity(verbosity: u8) {
+    // Fails if the logger is already set, in which case it is this one.
+    let _ = log::set_logger(&STDERR);
+    log::set_max_level(match verbosity {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    });
+}
+
+/// The 1-based number of `case`, which must be one of `cases`, for labelling diagnostics.
+pub fn case_number<C>(cases: &[C], case: &C) -> usize {
+    cases
+        .iter()
+        .position(|other| std::ptr::eq(other, case))
+        .expect("case is not one of the cases")
+        + 1
+}
@@ -0,0 +1,336 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{
+    Allocations, Comparison, Cost, InputError, Limit, LimitError, Report, ScanError, Verdict,
+    cases, count_allocations, solve_cases, timed,
+};
+
+/// A contest problem, split into parsing, solving a single case and formatting its answer.
+pub trait Problem {
+    /// Letter identifying the problem within its contest, e.g. `'a'` for `problem-a`.
+    const LETTER: char;
+
+    /// The input the problem is graded on, compiled into the crate.
+    const INPUT: &'static str;
+
+    /// How answers are checked against the expected output.
+    const COMPARISON: Comparison = Comparison::Tokens;
+
+    /// Limits on the input the solver relies on, checked by [`Problem::validate_case`].
+    const LIMITS: &'static [Limit] = &[];
+
+    /// Whether cases take long enough to be worth solving in parallel; see [`solve_cases`].
+    const PARALLEL: bool = false;
+
+    /// A parsed input file, viewable as the list of cases it contains.
+    type Input: AsRef<[Self::Case]> + Sync;
+
+    /// A single test case.
+    type Case: Sync;
+
+    /// The answer to a single test case.
+    type Output: Send;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError>;
+
+    /// Check a single case against [`Problem::LIMITS`].
+    fn validate_case(_input: &Self::Input, _case: &Self::Case) -> Result<(), LimitError> {
+        Ok(())
+    }
+
+    /// Check every case of an input against [`Problem::LIMITS`].
+    fn validate(input: &Self::Input) -> Result<(), LimitError> {
+        for (i, case) in input.as_ref().iter().enumerate() {
+            Self::validate_case(input, case).map_err(|err| err.in_case(i + 1))?;
+        }
+        Ok(())
+    }
+
+    /// Parse an input and make sure the solver supports it.
+    fn parse_valid(input: &str) -> Result<Self::Input, InputError> {
+        let input = Self::parse(input)?;
+        Self::validate(&input)?;
+        Ok(input)
+    }
+
+    /// Work shared by every case, done before solving any; answers must not depend on it.
+    fn prepare(_input: &mut Self::Input) {}
+
+    fn solve_case(input: &Self::Input, case: &Self::Case) -> Self::Output;
+
+    /// A rough measure of how big a case is, shown next to its time in profiles.
+    fn case_size(_input: &Self::Input, _case: &Self::Case) -> Option<usize> {
+        None
+    }
+
+    /// Whether a case is too slow for the default golden test; see [`Problem::check_golden`].
+    fn slow_case(_input: &Self::Input, _case: &Self::Case) -> bool {
+        false
+    }
+
+    /// Whether an answer is known to be optimal, i.e. its search did not run out of budget.
+    fn optimal(_output: &Self::Output) -> bool {
+        true
+    }
+
+    /// Format the answer to a single case, without its `Case #N: ` prefix.
+    fn format_case(output: &Self::Output) -> String;
+
+    /// Solve every case of an input, in order, along with what each one cost.
+    fn solve_timed(input: &Self::Input) -> Vec<(Self::Output, Cost)> {
+        solve_cases(input.as_ref(), Self::PARALLEL, |case| {
+            Self::solve_case(input, case)
+        })
+    }
+
+    /// Solve every case of an input, in order.
+    fn solve(input: &Self::Input) -> Vec<Self::Output> {
+        Self::solve_timed(input)
+            .into_iter()
+            .map(|(output, _)| output)
+            .collect()
+    }
+
+    /// Judge the answer to a single case against the expected one, if there is one.
+    fn judge_case(
+        _input: &Self::Input,
+        _case: &Self::Case,
+        expected: Option<&str>,
+        found: &str,
+    ) -> Verdict {
+        match expected {
+            Some(expected) => checker::check_case(Self::COMPARISON, expected, found),
+            None => Verdict::UnexpectedCase,
+        }
+    }
+
+    /// Check a whole output for an input against the expected one, case by case.
+    fn check(input: &str, expected: &str, found: &str) -> Result<Report, ScanError> {
+        let input = Self::parse(input)?;
+        let cases = input.as_ref();
+        Ok(checker::check_with(
+            expected,
+            found,
+            |n, expected, found| match n.checked_sub(1).and_then(|i| cases.get(i)) {
+                Some(case) => Self::judge_case(&input, case, expected, found),
+                None => Verdict::UnexpectedCase,
+            },
+        ))
+    }
+
+    /// Check the answers to [`Problem::INPUT`] against `expected`, leaving out slow cases, and
+    /// skipping [`Problem::prepare`], unless `slow` is set.
+    fn check_golden(expected: &str, slow: bool) -> Result<Report, InputError> {
+        if slow {
+            let found = Self::run(Self::INPUT)?;
+            return Ok(Self::check(Self::INPUT, expected, &found)?);
+        }
+
+        let input = Self::parse_valid(Self::INPUT)?;
+        let quick: Vec<usize> = (1..=input.as_ref().len())
+            .filter(|&n| !Self::slow_case(&input, &input.as_ref()[n - 1]))
+            .collect();
+        let found: String = quick
+            .iter()
+            .map(|&n| {
+                let output = Self::solve_case(&input, &input.as_ref()[n - 1]);
+                format!("Case #{n}: {}\n", Self::format_case(&output))
+            })
+            .collect();
+        let expected: String = checker::split_cases(expected)
+            .into_iter()
+            .filter(|(n, _)| quick.contains(n))
+            .map(|(n, answer)| format!("Case #{n}:{}\n", answer.trim_end()))
+            .collect();
+        Ok(Self::check(Self::INPUT, &expected, &found)?)
+    }
+
+    /// Format the answers to every case of an input.
+    fn format(outputs: &[Self::Output]) -> String {
+        cases(outputs.iter().map(Self::format_case))
+    }
+
+    /// Parse, solve and format an input in one go.
+    fn run(input: &str) -> Result<String, InputError> {
+        let mut input = Self::parse_valid(input)?;
+        Self::prepare(&mut input);
+        Ok(Self::format(&Self::solve(&input)))
+    }
+}
+
+/// The formatted output of an input, along with where the time went.
+#[derive(Debug, Clone)]
+pub struct TimedRun {
+    pub output: String,
+    pub parse: Duration,
+    /// Time spent in [`Problem::prepare`], which no case's time includes.
+    pub prepare: Duration,
+    /// Wall time of solving every case, which may be solved in parallel.
+    pub solve: Duration,
+    pub cases: Vec<Duration>,
+    /// [`Problem::case_size`] of each case.
+    pub sizes: Vec<Option<usize>>,
+    /// What each case allocated, if allocations are being counted.
+    pub case_allocations: Vec<Option<Allocations>>,
+    /// What the whole run allocated, if allocations are being counted.
+    pub allocations: Option<Allocations>,
+    /// 1-based numbers of the cases whose search ran out of budget.
+    pub timed_out: Vec<usize>,
+    pub format: Duration,
+}
+
+impl TimedRun {
+    pub fn total(&self) -> Duration {
+        self.parse + self.prepare + self.solve + self.format
+    }
+}
+
+/// Object-safe view of a [`Problem`], used to keep problems of different types in one registry.
+pub trait Solver: Sync {
+    fn letter(&self) -> char;
+
+    /// Equivalent to [`Problem::INPUT`].
+    fn input(&self) -> &'static str;
+
+    /// Equivalent to [`Problem::COMPARISON`].
+    fn comparison(&self) -> Comparison;
+
+    /// Equivalent to [`Problem::LIMITS`].
+    fn limits(&self) -> &'static [Limit];
+
+    /// Equivalent to [`Problem::run`].
+    fn solve_str(&self, input: &str) -> Result<String, InputError>;
+
+    /// Like [`Solver::solve_str`], but timing each step and each case.
+    fn run_timed(&self, input: &str) -> Result<TimedRun, InputError>;
+
+    /// Hand each step of solving `input` to `stages`, e.g. to benchmark them separately.
+    fn run_stages(&self, input: &str, stages: &mut dyn Stages) -> Result<(), InputError>;
+
+    /// Equivalent to [`Problem::check`].
+    fn check_output(&self, input: &str, expected: &str, found: &str) -> Result<Report, ScanError>;
+
+    /// Equivalent to [`Problem::check_golden`].
+    fn check_golden(&self, expected: &str, slow: bool) -> Result<Report, InputError>;
+}
+
+impl<P: Problem + Sync> Solver for P {
+    fn letter(&self) -> char {
+        P::LETTER
+    }
+
+    fn input(&self) -> &'static str {
+        P::INPUT
+    }
+
+    fn comparison(&self) -> Comparison {
+        P::COMPARISON
+    }
+
+    fn limits(&self) -> &'static [Limit] {
+        P::LIMITS
+    }
+
+    fn solve_str(&self, input: &str) -> Result<String, InputError> {
+        P::run(input)
+    }
+
+    fn run_timed(&self, input: &str) -> Result<TimedRun, InputError> {
+        let (run, allocations) = count_allocations(|| {
+            let (parsed, parse) = timed(|| P::parse_valid(input));
+            let mut parsed = parsed?;
+            let ((), prepare) = timed(|| P::prepare(&mut parsed));
+
+            let start = Instant::now();
+            let (outputs, costs): (Vec<_>, Vec<Cost>) = P::solve_timed(&parsed).into_iter().unzip();
+            let solve = start.elapsed();
+            let timed_out = outputs
+                .iter()
+                .enumerate()
+                .filter(|(_, output)| !P::optimal(output))
+                .map(|(i, _)| i + 1)
+                .collect();
+
+            let (output, format) = timed(|| P::format(&outputs));
+            let sizes = parsed
+                .as_ref()
+                .iter()
+                .map(|case| P::case_size(&parsed, case))
+                .collect();
+
+            Ok(TimedRun {
+                output,
+                parse,
+                prepare,
+                solve,
+                cases: costs.iter().map(|cost| cost.time).collect(),
+                sizes,
+                case_allocations: costs.iter().map(|cost| cost.allocations).collect(),
+                allocations: None,
+                timed_out,
+                format,
+            })
+        });
+        run.map(|run| TimedRun { allocations, ..run })
+    }
+
+    fn run_stages(&self, input: &str, stages: &mut dyn Stages) -> Result<(), InputError> {
+        // Nothing is prepared or solved up front, so that stages a benchmark filters out cost
+        // nothing: preparing b's input alone takes minutes.
+        let mut parsed = P::parse_valid(input)?;
+        let mut is_prepared = false;
+        stages.parse(&mut || {
+            black_box(P::parse(black_box(input)).ok());
+        });
+        stages.prepare(&mut || {
+            P::prepare(black_box(&mut parsed));
+            is_prepared = true;
+        });
+
+        for i in 0..parsed.as_ref().len() {
+            stages.solve_case(i + 1, &mut || {
+                let parsed = prepared::<P>(&mut parsed, &mut is_prepared);
+                black_box(P::solve_case(parsed, black_box(&parsed.as_ref()[i])));
+            });
+        }
+
+        let mut outputs = None;
+        stages.format(&mut || {
+            let outputs = outputs
+                .get_or_insert_with(|| P::solve(prepared::<P>(&mut parsed, &mut is_prepared)));
+            black_box(P::format(black_box(outputs)));
+        });
+        Ok(())
+    }
+
+    fn check_output(&self, input: &str, expected: &str, found: &str) -> Result<Report, ScanError> {
+        P::check(input, expected, found)
+    }
+
+    fn check_golden(&self, expected: &str, slow: bool) -> Result<Report, InputError> {
+        P::check_golden(expected, slow)
+    }
+}
+
+/// `parsed`, after running [`Problem::prepare`] on it unless that is already done.
+fn prepared<'a, P: Problem>(parsed: &'a mut P::Input, is_prepared: &mut bool) -> &'a P::Input {
+    if !*is_prepared {
+        P::prepare(parsed);
+        *is_prepared = true;
+    }
+    parsed
+}
+
+/// Receives each step of solving an input from [`Solver::run_stages`] as a repeatable closure.
+pub trait Stages {
+    fn parse(&mut self, parse: &mut dyn FnMut());
+
+    /// Runs [`Problem::prepare`] on the parsed input, which every later step then uses.
+    fn prepare(&mut self, prepare: &mut dyn FnMut());
+
+    /// Called for every case in order, with its 1-based number.
+    fn solve_case(&mut self, case: usize, solve: &mut dyn FnMut());
+
+    fn format(&mut self, format: &mut dyn FnMut());
+}
@@ -0,0 +1,90 @@
+//! A progress line for inputs that take a while, drawn on stderr as cases get solved.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Set by [`show_progress`]; off by default so that tests and benchmarks stay quiet.
+static SHOW: AtomicBool = AtomicBool::new(false);
+
+/// Draw progress while solving from now on, as long as stdout is a terminal.
+pub fn show_progress(show: bool) {
+    SHOW.store(show, Ordering::Relaxed);
+}
+
+/// Whether [`Progress`] is drawn at all: only when asked for and stdout is a terminal.
+pub fn progress_enabled() -> bool {
+    SHOW.load(Ordering::Relaxed) && io::stdout().is_terminal()
+}
+
+/// Cases done out of a total, with an estimate of the time left, drawn for slow inputs only.
+#[derive(Debug)]
+pub struct Progress {
+    total: usize,
+    done: AtomicUsize,
+    start: Instant,
+    visible: bool,
+    /// When the line was last drawn, if it has been.
+    drawn: Mutex<Option<Instant>>,
+}
+
+impl Progress {
+    /// How long to wait before drawing anything.
+    const DELAY: Duration = Duration::from_millis(250);
+
+    /// How long to wait between redraws.
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            done: AtomicUsize::new(0),
+            start: Instant::now(),
+            visible: progress_enabled(),
+            drawn: Mutex::new(None),
+        }
+    }
+
+    /// Count one more case as done, redrawing the line if it is due.
+    pub fn inc(&self) {
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        if !self.visible || done >= self.total {
+            return;
+        }
+        // Whoever holds the lock is already drawing, so there's no need to wait for it.
+        let Ok(mut drawn) = self.drawn.try_lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let due = match *drawn {
+            Some(last) => last + Self::INTERVAL,
+            None => self.start + Self::DELAY,
+        };
+        if now < due {
+            return;
+        }
+        *drawn = Some(now);
+
+        let elapsed = now - self.start;
+        let left = elapsed.mul_f64((self.total - done) as f64 / done as f64);
+        eprint!(
+            "\r\x1b[2K{done}/{} cases, {:.0?} elapsed, about {:.0?} left",
+            self.total, elapsed, left
+        );
+        let _ = io::stderr().flush();
+    }
+
+    /// Clear the line, if it was ever drawn.
+    pub fn finish(self) {
+        let drawn = self
+            .drawn
+            .into_inner()
+            .unwrap_or_else(|err| err.into_inner());
+        if drawn.is_some() {
+            eprint!("\r\x1b[2K");
+            let _ = io::stderr().flush();
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::{any::type_name, error::Error, fmt, str::FromStr};
+
+/// Reader for typed, whitespace-separated tokens and whole lines of an input file.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    lines: std::str::Lines<'a>,
+
+    /// Line currently being read, if any.
+    current: Option<&'a str>,
+
+    /// Byte offset of the next unread character in `current`.
+    offset: usize,
+
+    /// 1-based number of the line most recently taken from `lines`.
+    line: usize,
+
+    /// Position of the most recently read token, used for error reporting.
+    last: (usize, usize),
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            lines: input.lines(),
+            current: None,
+            offset: 0,
+            line: 0,
+            last: (1, 1),
+        }
+    }
+
+    /// Make sure there is a current line to read from; returns false at the end of the input.
+    fn fill(&mut self) -> bool {
+        if self.current.is_none() {
+            let Some(line) = self.lines.next() else {
+                return false;
+            };
+            self.current = Some(line);
+            self.offset = 0;
+            self.line += 1;
+        }
+        true
+    }
+
+    fn end_of_input(&self, expected: &'static str) -> ScanError {
+        ScanError {
+            case: None,
+            line: self.line + 1,
+            column: 1,
+            kind: ScanErrorKind::UnexpectedEof { expected },
+        }
+    }
+
+    /// An error `offset` characters into the most recently read token or line.
+    pub fn error_at(&self, offset: usize, description: impl Into<String>) -> ScanError {
+        ScanError {
+            case: None,
+            line: self.last.0,
+            column: self.last.1 + offset,
+            kind: ScanErrorKind::Invalid(description.into()),
+        }
+    }
+
+    /// Like [`Scanner::error_at`], pointing at the start of the most recently read token or line.
+    pub fn error(&self, description: impl Into<String>) -> ScanError {
+        self.error_at(0, description)
+    }
+
+    fn read_token(&mut self, expected: &'static str) -> Result<&'a str, ScanError> {
+        loop {
+            if !self.fill() {
+                return Err(self.end_of_input(expected));
+            }
+            let line = self.current.unwrap();
+            let rest = &line[self.offset..];
+            let trimmed = rest.trim_start();
+            if trimmed.is_empty() {
+                self.current = None;
+                continue;
+            }
+
+            let start = self.offset + (rest.len() - trimmed.len());
+            let end = start + trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+            self.last = (self.line, line[..start].chars().count() + 1);
+            self.offset = end;
+
+            // Don't leave a fully consumed line behind, so that a following `line()` call moves
+            // on to the next one.
+            if line[end..].trim().is_empty() {
+                self.current = None;
+            }
+
+            return Ok(&line[start..end]);
+        }
+    }
+
+    /// Read the next whitespace-separated token.
+    pub fn token(&mut self) -> Result<&'a str, ScanError> {
+        self.read_token("token")
+    }
+
+    /// Read the next token and parse it as a `T`.
+    pub fn read<T>(&mut self) -> Result<T, ScanError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let token = self.read_token(type_name::<T>())?;
+        token.parse().map_err(|err: T::Err| ScanError {
+            case: None,
+            line: self.last.0,
+            column: self.last.1,
+            kind: ScanErrorKind::InvalidToken {
+                token: token.to_owned(),
+                expected: type_name::<T>(),
+                reason: err.to_string(),
+            },
+        })
+    }
+
+    /// Read a tuple of tokens, e.g. `scanner.tuple::<(usize, usize)>()`.
+    pub fn tuple<T: FromTokens>(&mut self) -> Result<T, ScanError> {
+        T::from_tokens(self)
+    }
+
+    /// Read `n` tokens of type `T`.
+    pub fn vec<T>(&mut self, n: usize) -> Result<Vec<T>, ScanError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        (0..n).map(|_| self.read()).collect()
+    }
+
+    /// Read the rest of the current line, or the next line if the current one has been consumed.
+    pub fn line(&mut self) -> Result<&'a str, ScanError> {
+        if !self.fill() {
+            return Err(self.end_of_input("line"));
+        }
+        let line = self.current.take().unwrap();
+        let rest = if self.offset == 0 {
+            line
+        } else {
+            line[self.offset..].trim_start()
+        };
+        let start = line.len() - rest.len();
+        self.last = (self.line, line[..start].chars().count() + 1);
+        Ok(rest)
+    }
+
+    /// Read all tokens on the rest of the current line as `T`s.
+    pub fn line_of<T>(&mut self) -> Result<Vec<T>, ScanError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let line = self.line()?;
+        let (line_number, column) = self.last;
+        Scanner::new(line)
+            .until_eof(Scanner::read)
+            .map_err(|err| ScanError {
+                case: None,
+                line: line_number,
+                column: column + err.column - 1,
+                ..err
+            })
+    }
+
+    /// Read `n` lines.
+    pub fn lines(&mut self, n: usize) -> Result<Vec<&'a str>, ScanError> {
+        (0..n).map(|_| self.line()).collect()
+    }
+
+    /// Read a grid of `height` rows, each of which must be exactly `width` bytes long.
+    pub fn grid(&mut self, height: usize, width: usize) -> Result<Vec<&'a [u8]>, ScanError> {
+        (0..height)
+            .map(|_| {
+                let row = self.line()?;
+                if row.len() != width {
+                    return Err(ScanError {
+                        case: None,
+                        line: self.last.0,
+                        column: self.last.1,
+                        kind: ScanErrorKind::WrongLength {
+                            expected: width,
+                            found: row.len(),
+                        },
+                    });
+                }
+                Ok(row.as_bytes())
+            })
+            .collect()
+    }
+
+    /// Read a count `n` followed by `n` items produced by `f`, tagging errors with their item.
+    pub fn counted<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ScanError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ScanError>,
+    {
+        let n = self.read::<usize>()?;
+        (0..n)
+            .map(|i| f(self).map_err(|err| err.in_case(i + 1)))
+            .collect()
+    }
+
+    /// Read items produced by `f` until the input is exhausted, tagging errors with their item.
+    pub fn until_eof<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ScanError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ScanError>,
+    {
+        let mut items = Vec::new();
+        while !self.is_empty() {
+            items.push(f(self).map_err(|err| err.in_case(items.len() + 1))?);
+        }
+        Ok(items)
+    }
+
+    /// Whether only whitespace is left in the input.
+    pub fn is_empty(&self) -> bool {
+        self.current
+            .is_none_or(|line| line[self.offset..].trim().is_empty())
+            && self.lines.clone().all(|line| line.trim().is_empty())
+    }
+}
+
+/// Types that can be read from a sequence of tokens; implemented for tuples of [`FromStr`] types.
+pub trait FromTokens: Sized {
+    fn from_tokens(scanner: &mut Scanner<'_>) -> Result<Self, ScanError>;
+}
+
+macro_rules! impl_from_tokens {
+    ($($t:ident),+) => {
+        impl<$($t),+> FromTokens for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: fmt::Display),+
+        {
+            fn from_tokens(scanner: &mut Scanner<'_>) -> Result<Self, ScanError> {
+                Ok(($(scanner.read::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_tokens!(A);
+impl_from_tokens!(A, B);
+impl_from_tokens!(A, B, C);
+impl_from_tokens!(A, B, C, D);
+impl_from_tokens!(A, B, C, D, E);
+impl_from_tokens!(A, B, C, D, E, F);
+
+/// A malformed or truncated input, along with the 1-based position where the problem was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+    /// The case being read, if the error happened inside one.
+    pub case: Option<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub kind: ScanErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanErrorKind {
+    UnexpectedEof {
+        expected: &'static str,
+    },
+
+    InvalidToken {
+        token: String,
+        expected: &'static str,
+        reason: String,
+    },
+
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+
+    /// Well-formed, but not something the problem allows, e.g. an unknown keyword.
+    Invalid(String),
+}
+
+impl ScanError {
+    /// Attribute the error to the given 1-based case.
+    pub fn in_case(self, case: usize) -> Self {
+        Self {
+            case: Some(case),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(case) = self.case {
+            write!(f, "case #{case}, ")?;
+        }
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ScanErrorKind::UnexpectedEof { expected } => {
+                write!(f, "unexpected end of input, expected {expected}")
+            }
+            ScanErrorKind::InvalidToken {
+                token,
+                expected,
+                reason,
+            } => write!(f, "expected {expected}, found {token:?} ({reason})"),
+            ScanErrorKind::WrongLength { expected, found } => {
+                write!(f, "expected a row of {expected} characters, found {found}")
+            }
+            ScanErrorKind::Invalid(description) => write!(f, "{description}"),
+        }
+    }
+}
+
+impl Error for ScanError {}
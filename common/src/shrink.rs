@@ -0,0 +1,203 @@
+//! Shrinking failing inputs down to minimal reproductions.
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once, PoisonError};
+
+use crate::{Generate, Generator, Mismatch, Problem, Reference, counted_input, differential};
+
+/// How solving an input went wrong.
+#[derive(Debug)]
+pub enum Failure {
+    Panicked { location: String, message: String },
+    Disagreed(Box<Mismatch>),
+}
+
+impl Failure {
+    /// Whether two failures look like the same bug: panics from one place, or two disagreements.
+    pub fn same_as(&self, other: &Failure) -> bool {
+        match (self, other) {
+            (Failure::Panicked { location: a, .. }, Failure::Panicked { location: b, .. }) => {
+                a == b
+            }
+            (Failure::Disagreed(_), Failure::Disagreed(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panicked { location, message } => {
+                write!(f, "panicked at {location}: {message}")
+            }
+            Failure::Disagreed(mismatch) => write!(f, "disagrees with the reference on {mismatch}"),
+        }
+    }
+}
+
+/// A problem whose failing inputs can be cut down to smaller ones that still fail.
+pub trait Shrink: Problem {
+    /// Every valid input one step smaller than `input`, biggest cuts first.
+    fn shrink(input: &str) -> Vec<String>;
+
+    /// Why solving `input` fails, if it does; by default only panics count.
+    fn failure(input: &str) -> Option<Failure> {
+        panic_failure::<Self>(input)
+    }
+}
+
+/// Object-safe view of a [`Shrink`] problem.
+pub trait Shrinker: Generator {
+    fn failure_of(&self, input: &str) -> Option<Failure>;
+
+    /// See [`minimize`].
+    fn minimize_input(&self, input: &str, failure: &Failure) -> String;
+}
+
+impl<P: Shrink + Generate + Sync> Shrinker for P {
+    fn failure_of(&self, input: &str) -> Option<Failure> {
+        P::failure(input)
+    }
+
+    fn minimize_input(&self, input: &str, failure: &Failure) -> String {
+        minimize::<P>(input, failure)
+    }
+}
+
+/// Panics while solving `input`, the default [`Shrink::failure`].
+pub fn panic_failure<P: Problem + ?Sized>(input: &str) -> Option<Failure> {
+    catch_panic(|| P::run(input)).err()
+}
+
+/// Panics or disagreements with the reference solver, for [`Shrink::failure`].
+pub fn differential_failure<P: Reference>(input: &str) -> Option<Failure> {
+    P::parse(input).ok()?;
+    match catch_panic(|| differential::<P, _>([input.to_owned()])) {
+        Ok(Ok(_)) => None,
+        Ok(Err(mismatch)) => Some(Failure::Disagreed(mismatch)),
+        Err(failure) => Some(failure),
+    }
+}
+
+/// Greedily shrink `input` for as long as it keeps failing the same way as `failure`.
+pub fn minimize<P: Shrink>(input: &str, failure: &Failure) -> String {
+    let mut input = input.to_owned();
+    'shrink: loop {
+        for candidate in P::shrink(&input) {
+            if P::failure(&candidate).is_some_and(|found| found.same_as(failure)) {
+                input = candidate;
+                continue 'shrink;
+            }
+        }
+        return input;
+    }
+}
+
+/// Number of [`catch_panic`] calls in progress, during which panics aren't printed.
+static CATCHING: AtomicUsize = AtomicUsize::new(0);
+
+/// Location and message of the last panic caught.
+static LAST_PANIC: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.load(Ordering::SeqCst) == 0 {
+                return default(info);
+            }
+            let location = info
+                .location()
+                .map_or_else(String::new, ToString::to_string);
+            let message = info.payload_as_str().unwrap_or("Box<dyn Any>").to_owned();
+            *LAST_PANIC.lock().unwrap_or_else(PoisonError::into_inner) = Some((location, message));
+        }));
+    });
+
+    CATCHING.fetch_add(1, Ordering::SeqCst);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.fetch_sub(1, Ordering::SeqCst);
+
+    result.map_err(|_| {
+        let (location, message) = LAST_PANIC
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+            .unwrap_or_default();
+        Failure::Panicked { location, message }
+    })
+}
+
+/// Every way of removing a run of `items`, from halves down to single items.
+pub fn removals<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let end = (start + chunk).min(items.len());
+            candidates.push([&items[..start], &items[end..]].concat());
+        }
+        chunk /= 2;
+    }
+    candidates
+}
+
+/// Split a counted input into the lines of each case, sized by `case_len` of its first line.
+pub fn counted_cases(
+    input: &str,
+    case_len: impl Fn(&str) -> Option<usize>,
+) -> Option<Vec<Vec<&str>>> {
+    let mut lines = input.lines();
+    let count: usize = lines.next()?.trim().parse().ok()?;
+    let mut cases = Vec::with_capacity(count);
+    for _ in 0..count {
+        let first = lines.next()?;
+        let mut case = vec![first];
+        for _ in 1..case_len(first)? {
+            case.push(lines.next()?);
+        }
+        cases.push(case);
+    }
+    Some(cases)
+}
+
+/// Shrink a counted input by removing cases, then each case on its own with `shrink_case`.
+pub fn shrink_counted(
+    input: &str,
+    case_len: impl Fn(&str) -> Option<usize>,
+    shrink_case: impl Fn(&[&str]) -> Vec<String>,
+) -> Vec<String> {
+    let Some(cases) = counted_cases(input, case_len) else {
+        return Vec::new();
+    };
+
+    let mut candidates: Vec<String> = removals(&cases)
+        .into_iter()
+        .map(|cases| counted_input(cases.iter().map(|case| case.join("\n"))))
+        .collect();
+    for (i, case) in cases.iter().enumerate() {
+        for smaller in shrink_case(case) {
+            candidates.push(counted_input(cases.iter().enumerate().map(|(j, case)| {
+                if i == j {
+                    smaller.clone()
+                } else {
+                    case.join("\n")
+                }
+            })));
+        }
+    }
+    candidates
+}
+
+/// Shrink an input with one case per line by removing lines.
+pub fn shrink_lines(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    removals(&lines)
+        .into_iter()
+        .map(|lines| lines.join("\n"))
+        .collect()
+}
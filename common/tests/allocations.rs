@@ -0,0 +1,27 @@
+use common::{Bytes, count_allocations};
+
+#[test]
+fn bytes_are_shown_in_binary_units() {
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+    assert_eq!(Bytes(3 << 30).to_string(), "3.00 GiB");
+}
+
+#[test]
+#[cfg(not(feature = "count-allocations"))]
+fn nothing_is_counted_by_default() {
+    assert_eq!(count_allocations(|| vec![0u8; 64]).1, None);
+}
+
+#[test]
+#[cfg(feature = "count-allocations")]
+fn nested_counts_keep_the_outer_peak() {
+    let ((_, inner), outer) = count_allocations(|| {
+        drop(std::hint::black_box(vec![0u8; 1 << 20]));
+        count_allocations(|| drop(std::hint::black_box(vec![0u8; 1 << 10])))
+    });
+    let (inner, outer) = (inner.unwrap(), outer.unwrap());
+    assert!(inner.count >= 1 && inner.bytes >= 1 << 10);
+    assert!(inner.peak < 1 << 20);
+    assert!(outer.count >= 2 && outer.peak >= 1 << 20);
+}
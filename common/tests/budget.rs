@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use common::{Budget, Countdown};
+
+#[test]
+fn unlimited_budget_never_runs_out() {
+    let mut countdown = Countdown::new(Budget::default());
+    assert!((0..100_000).all(|_| countdown.tick()));
+    assert!(countdown.finish(()).optimal);
+}
+
+#[test]
+fn node_budget_runs_out_after_that_many_nodes() {
+    let mut countdown = Countdown::new(Budget {
+        time: None,
+        nodes: Some(10),
+    });
+    assert!((0..10).all(|_| countdown.tick()));
+    assert!(!countdown.tick());
+    assert!(
+        !countdown.tick(),
+        "an exhausted budget should stay exhausted"
+    );
+    assert_eq!(countdown.visited(), 11);
+    assert!(!countdown.finish(()).optimal);
+}
+
+#[test]
+fn time_budget_runs_out() {
+    let mut countdown = Countdown::new(Budget {
+        time: Some(Duration::from_millis(1)),
+        nodes: None,
+    });
+    std::thread::sleep(Duration::from_millis(2));
+    assert!((0..100_000).any(|_| !countdown.tick()));
+    assert!(countdown.exhausted());
+}
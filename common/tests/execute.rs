@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+#[test]
+fn parallel_answers_stay_in_case_order() {
+    let cases: Vec<u64> = (0..64).collect();
+    let solved = common::solve_cases(&cases, true, |&case| {
+        // Make earlier cases finish last.
+        std::thread::sleep(Duration::from_micros(64 - case));
+        case * case
+    });
+    let answers: Vec<u64> = solved.into_iter().map(|(answer, _)| answer).collect();
+    assert_eq!(
+        answers,
+        cases.iter().map(|case| case * case).collect::<Vec<_>>()
+    );
+}
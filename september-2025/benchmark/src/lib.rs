@@ -1 +0,0 @@
-// this space intentionally left blank
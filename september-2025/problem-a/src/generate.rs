@@ -0,0 +1,31 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct NameConstraints {
+        cases: usize = 16,
+        min_len: usize = 1,
+        max_len: usize = 15,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = NameConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &NameConstraints) -> String {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        counted_input((0..constraints.cases).map(|_| {
+            let len = rng.random_range(constraints.min_len..=constraints.max_len);
+            random_word(rng, LETTERS, len)
+        }))
+    }
+}
+
+impl Shrink for Solution {
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_counted(input, |_| Some(1), |_| Vec::new())
+    }
+}
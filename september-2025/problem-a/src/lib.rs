@@ -1,14 +1,41 @@
-use std::fmt::Display;
+use common::*;
 
-use common::cases;
+mod generate;
 
-pub fn solve() -> impl Display {
-    cases(include_str!("input.txt").lines().skip(1).map(|line| {
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'a';
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input = Vec<String>;
+    type Case = String;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).counted(|scanner| {
+            let name = scanner.line()?;
+            if let Some(i) = name.chars().position(|c| !c.is_ascii_alphabetic()) {
+                return Err(scanner.error_at(i, "names may only contain letters"));
+            }
+            Ok(name.to_owned())
+        })
+    }
+
+    fn solve_case(_input: &Self::Input, name: &String) -> u32 {
         let mut mask = 0u32;
-        for c in line.bytes() {
+        for c in name.bytes() {
             mask |= 1 << (c.to_ascii_lowercase() - b'a');
         }
 
         100 - 5 * mask.count_ones()
-    }))
+    }
+
+    fn case_size(_input: &Self::Input, name: &String) -> Option<usize> {
+        Some(name.len())
+    }
+
+    fn format_case(score: &u32) -> String {
+        score.to_string()
+    }
 }
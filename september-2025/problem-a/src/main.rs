@@ -1,3 +1,3 @@
 fn main() {
-    println!("{}", problem_a::solve());
+    common::main(&september_2025_problem_a::Solution);
 }
@@ -0,0 +1,19 @@
+use common::{Problem, ScanErrorKind};
+use september_2025_problem_a::Solution;
+
+#[test]
+fn non_letter_in_name() {
+    let Err(err) = Solution::parse("2\nabc\nab1c") else {
+        panic!("input should not parse");
+    };
+    assert_eq!((err.case, err.line, err.column), (Some(2), 3, 3));
+    assert!(matches!(err.kind, ScanErrorKind::Invalid(_)));
+}
+
+#[test]
+fn non_ascii_letter_in_name() {
+    let Err(err) = Solution::parse("1\nabÅsa") else {
+        panic!("input should not parse");
+    };
+    assert_eq!((err.line, err.column), (2, 3));
+}
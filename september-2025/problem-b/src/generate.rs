@@ -0,0 +1,34 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct TournamentConstraints {
+        cases: usize = 30,
+        min_players: usize = 2,
+        /// Enumerating every tournament of 6 players already takes minutes.
+        max_players: usize = 6,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = TournamentConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &TournamentConstraints) -> String {
+        (0..constraints.cases)
+            .map(|_| {
+                let n = rng.random_range(constraints.min_players..=constraints.max_players);
+                let k = rng.random_range(0..10);
+                format!("{n} {k}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Shrink for Solution {
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_lines(input)
+    }
+}
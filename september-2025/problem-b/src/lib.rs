@@ -1,63 +1,134 @@
-use std::fmt::Display;
-
 use itertools::Itertools;
 
 use common::*;
 use rayon::prelude::*;
 
-#[inline]
-pub fn solve() -> impl Display {
-    let ks: Vec<[_; 10]> = (2..=6)
+mod generate;
+
+/// For `n` players, count the round-robin tournaments whose score spread is greater than each `k`.
+fn distribution(n: usize) -> [u32; 10] {
+    let game_map: Vec<_> = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|(i, j)| i != j)
+        .collect();
+
+    (0..1 << (n * (n - 1)))
         .into_par_iter()
-        .map(|n| {
-            let game_map: Vec<_> = (0..n)
-                .flat_map(|i| (0..n).map(move |j| (i, j)))
-                .filter(|(i, j)| i != j)
-                .map(|(i, j)| (i as usize, j as usize))
-                .collect();
-
-            (0..1 << (n * (n - 1)))
-                .into_par_iter()
-                .map(|mut combo: u32| {
-                    let mut scores = [0; 6];
-                    for &(i, j) in &game_map {
-                        scores[i as usize] += combo & 1;
-                        scores[j as usize] += 1 - (combo & 1);
-                        combo >>= 1;
-                    }
-                    let (min, max) = scores.into_iter().take(n).minmax().into_option().unwrap();
-                    max - min
-                })
-                .fold(
-                    || [0u32; 10],
-                    |mut acc, delta| {
-                        acc.iter_mut()
-                            .take(delta as usize)
-                            .for_each(|count| *count += 1);
-
-                        acc
-                    },
-                )
-                .reduce(
-                    || [0; 10],
-                    |mut a, b| {
-                        a.iter_mut().zip(b).for_each(|(cell, n)| *cell += n);
-                        a
-                    },
-                )
+        .map(|mut combo: u32| {
+            let mut scores = [0; 6];
+            for &(i, j) in &game_map {
+                scores[i] += combo & 1;
+                scores[j] += 1 - (combo & 1);
+                combo >>= 1;
+            }
+            let (min, max) = scores.into_iter().take(n).minmax().into_option().unwrap();
+            max - min
         })
-        .collect();
+        .fold(
+            || [0u32; 10],
+            |mut acc, delta| {
+                acc.iter_mut()
+                    .take(delta as usize)
+                    .for_each(|count| *count += 1);
+
+                acc
+            },
+        )
+        .reduce(
+            || [0; 10],
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(cell, n)| *cell += n);
+                a
+            },
+        )
+}
+
+const PLAYERS: Limit = Limit {
+    what: "player count",
+    min: 2,
+    max: 6,
+    reason: "every tournament is enumerated as a u32 of game results",
+};
+const SPREAD: Limit = Limit {
+    what: "score spread",
+    min: 0,
+    max: 9,
+    reason: "only spreads below 10 are counted",
+};
+
+pub struct Input {
+    cases: Vec<(usize, usize)>,
+
+    /// [`distribution`] of each player count from [`PLAYERS`]' minimum up, for the counts some
+    /// case asks about; filled by [`Problem::prepare`].
+    distributions: Vec<Option<[u32; 10]>>,
+}
 
-    cases(
-        include_str!("input.txt")
-            .lines()
-            .map(|line| {
-                let (n, k) = line.split_once(' ').unwrap();
-                let n: usize = n.parse().unwrap();
-                let k: usize = k.parse().unwrap();
+impl AsRef<[(usize, usize)]> for Input {
+    fn as_ref(&self) -> &[(usize, usize)] {
+        &self.cases
+    }
+}
+
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'b';
+    const INPUT: &'static str = include_str!("input.txt");
+    const LIMITS: &'static [Limit] = &[PLAYERS, SPREAD];
+
+    type Input = Input;
+    type Case = (usize, usize);
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Ok(Input {
+            cases: Scanner::new(input).until_eof(Scanner::tuple)?,
+            distributions: Vec::new(),
+        })
+    }
 
-                ks[n - 2][k]
+    fn validate_case(_input: &Self::Input, &(n, k): &(usize, usize)) -> Result<(), LimitError> {
+        PLAYERS.check(n)?;
+        SPREAD.check(k)
+    }
+
+    /// Every input asks about the same handful of player counts, so enumerate each one's
+    /// tournaments once up front instead of once per case. Counts no case asks about are skipped,
+    /// which keeps small inputs like the sample fast.
+    fn prepare(input: &mut Self::Input) {
+        let (min, max) = (PLAYERS.min as usize, PLAYERS.max as usize);
+        input.distributions = (min..=max)
+            .into_par_iter()
+            .map(|n| {
+                input
+                    .cases
+                    .iter()
+                    .any(|&(m, _)| m == n)
+                    .then(|| distribution(n))
             })
-            .collect::<Vec<_>>(),
-    )
+            .collect();
+    }
+
+    fn solve_case(input: &Self::Input, &(n, k): &(usize, usize)) -> u32 {
+        let prepared = input
+            .distributions
+            .get(n - PLAYERS.min as usize)
+            .copied()
+            .flatten();
+        prepared.unwrap_or_else(|| distribution(n))[k]
+    }
+
+    /// Six players have 2^30 tournaments to enumerate.
+    fn slow_case(_input: &Self::Input, &(n, _): &(usize, usize)) -> bool {
+        n == PLAYERS.max as usize
+    }
+
+    fn case_size(_input: &Self::Input, &(n, _): &(usize, usize)) -> Option<usize> {
+        Some(n)
+    }
+
+    fn format_case(count: &u32) -> String {
+        count.to_string()
+    }
 }
@@ -0,0 +1,73 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct KeywordConstraints {
+        keywords: usize = 100,
+        max_keyword_len: usize = 20,
+        actions: usize = 1000,
+        /// How many of the lowercase letters, from `a` on, keywords and typed text are made of;
+        /// fewer letters means more shared prefixes. Capped at 26.
+        letters: usize = 26,
+        /// Chance of each action being a backspace.
+        backspace_chance: f64 = 0.2,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = KeywordConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &KeywordConstraints) -> String {
+        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+        let alphabet = &ALPHABET[..constraints.letters.clamp(1, ALPHABET.len())];
+
+        let mut input = String::new();
+        for _ in 0..constraints.keywords {
+            let len = rng.random_range(1..=constraints.max_keyword_len);
+            input.push_str(&random_word(rng, alphabet, len));
+            input.push('\n');
+        }
+        input.push_str("=====\n");
+        for _ in 0..constraints.actions {
+            if rng.random_bool(constraints.backspace_chance) {
+                input.push('<');
+            } else {
+                input.push_str(&random_word(rng, alphabet, 1));
+            }
+        }
+        input
+    }
+}
+
+impl Shrink for Solution {
+    /// Remove keywords, then typed actions.
+    fn shrink(input: &str) -> Vec<String> {
+        let Some((keywords, actions)) = input.split_once("=====\n") else {
+            return Vec::new();
+        };
+        let keywords: Vec<&str> = keywords.lines().collect();
+        let actions: Vec<char> = actions.trim().chars().collect();
+
+        let render = |keywords: &[&str], actions: &[char]| {
+            let mut input: String = keywords
+                .iter()
+                .map(|keyword| format!("{keyword}\n"))
+                .collect();
+            input.push_str("=====\n");
+            input.extend(actions);
+            input
+        };
+        let mut candidates: Vec<String> = removals(&keywords)
+            .iter()
+            .map(|keywords| render(keywords, &actions))
+            .collect();
+        candidates.extend(
+            removals(&actions)
+                .iter()
+                .map(|actions| render(&keywords, actions)),
+        );
+        candidates
+    }
+}
@@ -1,40 +1,100 @@
-use std::fmt::{Display, Write};
-
-#[inline]
-pub fn solve() -> impl Display {
-    let mut lines = include_str!("input.txt").lines();
-    let keywords = lines
-        .by_ref()
-        .take_while(|&line| line != "=====")
-        .collect::<Vec<_>>();
-    let actions = lines.next().unwrap().trim().bytes();
-
-    let mut needle = String::new();
-    let mut output = String::new();
-    writeln!(output, "Case #1:").unwrap();
-
-    for action in actions {
-        match action {
-            b'a'..=b'z' => {
-                needle.push(action as char);
+use std::fmt::Write;
+
+use common::*;
+
+mod generate;
+
+pub struct Input {
+    keywords: Vec<String>,
+
+    /// The typed actions; there's only ever one case.
+    actions: [String; 1],
+}
+
+impl AsRef<[String]> for Input {
+    fn as_ref(&self) -> &[String] {
+        &self.actions
+    }
+}
+
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'c';
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input = Input;
+    type Case = String;
+    type Output = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        let mut scanner = Scanner::new(input);
+        let mut keywords = Vec::new();
+        loop {
+            let line = scanner.line()?;
+            if line == "=====" {
+                break;
             }
+            keywords.push(line.to_owned());
+        }
+        let line = scanner.line()?;
+        let leading = line.chars().take_while(|c| c.is_whitespace()).count();
+        let actions = line.trim().to_owned();
+        if let Some(i) = actions.chars().position(|c| !matches!(c, 'a'..='z' | '<')) {
+            return Err(scanner
+                .error_at(leading + i, "actions may only be lowercase letters and '<'")
+                .in_case(1));
+        }
+
+        Ok(Input {
+            keywords,
+            actions: [actions],
+        })
+    }
 
-            b'<' => {
+    fn solve_case(input: &Input, actions: &String) -> Vec<usize> {
+        let mut needle = String::new();
+        let mut counts = Vec::new();
+
+        for action in actions.bytes() {
+            if action == b'<' {
                 let _ = needle.pop();
+            } else {
+                needle.push(action as char);
             }
 
-            _ => unreachable!("unexpected item in bagging area: {action:?}"),
+            if needle.len() >= 3 {
+                counts.push(
+                    input
+                        .keywords
+                        .iter()
+                        .filter(|kw| kw.starts_with(&needle))
+                        .count(),
+                );
+            }
         }
 
-        if needle.len() >= 3 {
-            writeln!(
-                output,
-                "{}",
-                keywords.iter().filter(|kw| kw.starts_with(&needle)).count()
-            )
-            .unwrap();
+        counts
+    }
+
+    fn case_size(_input: &Self::Input, actions: &String) -> Option<usize> {
+        Some(actions.len())
+    }
+
+    fn format_case(counts: &Vec<usize>) -> String {
+        let mut output = String::new();
+        for count in counts {
+            writeln!(output, "{count}").unwrap();
         }
+        output
     }
 
-    output
+    /// The counts start on the line after the `Case #1:` header rather than on the same line.
+    fn format(outputs: &[Vec<usize>]) -> String {
+        let mut output = String::new();
+        for (i, counts) in outputs.iter().enumerate() {
+            write!(output, "Case #{}:\n{}", i + 1, Self::format_case(counts)).unwrap();
+        }
+        output
+    }
 }
@@ -0,0 +1,19 @@
+use common::{Problem, ScanErrorKind};
+use september_2025_problem_c::Solution;
+
+#[test]
+fn unknown_action() {
+    let Err(err) = Solution::parse("foo\n=====\nabc<d!") else {
+        panic!("input should not parse");
+    };
+    assert_eq!((err.case, err.line, err.column), (Some(1), 3, 6));
+    assert!(matches!(err.kind, ScanErrorKind::Invalid(_)));
+}
+
+#[test]
+fn column_counts_characters() {
+    let Err(err) = Solution::parse("foo\n=====\n  ab<é") else {
+        panic!("input should not parse");
+    };
+    assert_eq!((err.line, err.column), (3, 6));
+}
@@ -0,0 +1,45 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+/// The largest `n` the reference solver is quick enough for.
+const REFERENCE_MAX_N: u64 = 6;
+
+constraints! {
+    pub struct ExponentConstraints {
+        cases: usize = 23,
+        min_n: u64 = 1,
+        max_n: u64 = 1_000_000_000,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = ExponentConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &ExponentConstraints) -> String {
+        (0..constraints.cases)
+            .map(|_| {
+                rng.random_range(constraints.min_n..=constraints.max_n)
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Shrink for Solution {
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_lines(input)
+    }
+
+    fn failure(input: &str) -> Option<Failure> {
+        // The reference counts rectangles one by one, so only small grids can be checked with it.
+        let small = Self::parse(input).is_ok_and(|ns| ns.iter().all(|&n| n <= REFERENCE_MAX_N));
+        if small {
+            differential_failure::<Self>(input)
+        } else {
+            panic_failure::<Self>(input)
+        }
+    }
+}
@@ -1,16 +1,26 @@
-use std::fmt::Display;
-
 use common::*;
 
 use num_modular::{ModularInteger, MontgomeryInt};
 
+mod generate;
+
 const M: u64 = 1_000_000_009;
 
-#[inline]
-pub fn solve() -> impl Display {
-    cases(include_str!("input.txt").lines().map(|line| {
-        let n = line.parse::<u64>().unwrap();
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'd';
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input = Vec<u64>;
+    type Case = u64;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).until_eof(Scanner::read)
+    }
 
+    fn solve_case(_input: &Self::Input, &n: &u64) -> u64 {
         let big_side = MontgomeryInt::new(2, &M).pow(&n);
 
         // Final formula derived from the problem analysis; I just started manually unrolling loops
@@ -22,5 +32,32 @@ pub fn solve() -> impl Display {
         let result = (big_side * (big_side + 1) / big_side.convert(2)).pow(&2);
 
         result.residue()
-    }))
+    }
+
+    fn case_size(_input: &Self::Input, &n: &u64) -> Option<usize> {
+        Some(n as usize)
+    }
+
+    fn format_case(residue: &u64) -> String {
+        residue.to_string()
+    }
+}
+
+impl Reference for Solution {
+    /// Count the rectangles in a `2^n` by `2^n` grid one by one, by their top left and bottom
+    /// right cells, which is only quick enough for `n <= 6` or so.
+    fn reference_case(_input: &Self::Input, &n: &u64) -> u64 {
+        let side = 1u64 << n;
+        let mut rectangles = 0;
+        for top in 0..side {
+            for left in 0..side {
+                for _bottom in top..side {
+                    for _right in left..side {
+                        rectangles += 1;
+                    }
+                }
+            }
+        }
+        rectangles % M
+    }
 }
@@ -1,4 +1,3 @@
 fn main() {
-    let output = problem_d::solve();
-    println!("{output}");
+    common::main(&september_2025_problem_d::Solution);
 }
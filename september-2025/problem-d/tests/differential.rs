@@ -0,0 +1,6 @@
+use september_2025_problem_d::Solution;
+
+#[test]
+fn differential() {
+    common::assert_differential::<Solution, _>((0..=6).map(|n| n.to_string()));
+}
@@ -0,0 +1,32 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct SizeConstraints {
+        cases: usize = 20,
+        min_n: u64 = 1,
+        max_n: u64 = 1_000_000_000_000,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = SizeConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &SizeConstraints) -> String {
+        (0..constraints.cases)
+            .map(|_| {
+                rng.random_range(constraints.min_n..=constraints.max_n)
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Shrink for Solution {
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_lines(input)
+    }
+}
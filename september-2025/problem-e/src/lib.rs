@@ -1,11 +1,23 @@
-use std::fmt::Display;
-
 use common::*;
 
-#[inline]
-pub fn solve() -> impl Display {
-    cases(include_str!("input.txt").lines().map(|line| {
-        let mut n = line.parse::<u64>().unwrap();
+mod generate;
+
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'e';
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input = Vec<u64>;
+    type Case = u64;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).until_eof(Scanner::read)
+    }
+
+    fn solve_case(_input: &Self::Input, &n: &u64) -> u64 {
+        let mut n = n;
 
         if n <= 3 {
             return n;
@@ -24,5 +36,13 @@ pub fn solve() -> impl Display {
         }
 
         result
-    }))
+    }
+
+    fn case_size(_input: &Self::Input, &n: &u64) -> Option<usize> {
+        Some(n as usize)
+    }
+
+    fn format_case(result: &u64) -> String {
+        result.to_string()
+    }
 }
@@ -0,0 +1,114 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct GridConstraints {
+        cases: usize = 17,
+        min_height: usize = 1,
+        max_height: usize = 50,
+        min_width: usize = 1,
+        /// Each row is kept as a `u64` bitmask, so at most 64.
+        max_width: usize = 50,
+        max_buildings: usize = 100,
+        /// Chance of each cell other than the enemy's being buildable.
+        buildable_chance: f64 = 0.5,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = GridConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &GridConstraints) -> String {
+        counted_input((0..constraints.cases).map(|_| {
+            let height = rng.random_range(constraints.min_height..=constraints.max_height);
+            let width = rng.random_range(constraints.min_width..=constraints.max_width);
+            let enemy = (rng.random_range(0..width), rng.random_range(0..height));
+
+            let mut buildable = 0;
+            let mut grid = String::new();
+            for y in 0..height {
+                grid.push('\n');
+                for x in 0..width {
+                    grid.push(if (x, y) == enemy {
+                        'M'
+                    } else if rng.random_bool(constraints.buildable_chance) {
+                        buildable += 1;
+                        '.'
+                    } else {
+                        'X'
+                    });
+                }
+            }
+
+            let most = constraints.max_buildings.min(buildable);
+            let buildings = rng.random_range(most.min(1)..=most);
+            format!("{height} {width} {buildings}{grid}")
+        }))
+    }
+}
+
+impl Shrink for Solution {
+    /// Remove cases, then rows and columns the enemy isn't on, then buildings.
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_counted(
+            input,
+            |header| Some(1 + header.split_whitespace().next()?.parse::<usize>().ok()?),
+            |case| {
+                let Some(buildings) = case[0]
+                    .split_whitespace()
+                    .nth(2)
+                    .and_then(|buildings| buildings.parse::<usize>().ok())
+                else {
+                    return Vec::new();
+                };
+                let rows: Vec<Vec<u8>> = case[1..]
+                    .iter()
+                    .map(|row| row.as_bytes().to_vec())
+                    .collect();
+                let has_enemy = |grid: &Vec<Vec<u8>>| grid.iter().flatten().any(|&c| c == b'M');
+
+                let mut grids: Vec<_> = removals(&rows).into_iter().filter(has_enemy).collect();
+                grids.extend(
+                    removals(&transpose(&rows))
+                        .into_iter()
+                        .filter(has_enemy)
+                        .map(|columns| transpose(&columns)),
+                );
+                let mut candidates: Vec<String> =
+                    grids.iter().map(|grid| render(grid, buildings)).collect();
+                if buildings > 1 {
+                    candidates.push(render(&rows, buildings - 1));
+                }
+                candidates
+            },
+        )
+    }
+
+    fn failure(input: &str) -> Option<Failure> {
+        differential_failure::<Self>(input)
+    }
+}
+
+fn transpose(grid: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    (0..grid[0].len())
+        .map(|x| grid.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+/// A case with the given grid, with no more buildings than it has room for.
+fn render(grid: &[Vec<u8>], buildings: usize) -> String {
+    let buildable = grid.iter().flatten().filter(|&&c| c == b'.').count();
+    let mut case = format!(
+        "{} {} {}",
+        grid.len(),
+        grid[0].len(),
+        buildings.min(buildable)
+    );
+    for row in grid {
+        case.push('\n');
+        case.push_str(std::str::from_utf8(row).unwrap());
+    }
+    case
+}
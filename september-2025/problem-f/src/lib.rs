@@ -1,35 +1,49 @@
-use std::{
-    cmp::Reverse,
-    fmt::{Display, Write},
-};
-
-use rayon::prelude::*;
+use std::{cmp::Reverse, fmt::Write};
 
 use common::*;
 
+mod generate;
+
 type Point = (usize, usize);
 type Row = u64;
 
+const WIDTH: Limit = Limit {
+    what: "grid width",
+    min: 1,
+    max: Row::BITS as u64,
+    reason: "rows are u64 bitmasks",
+};
+const BUILDINGS: Limit = Limit {
+    what: "building count",
+    min: 0,
+    max: u64::MAX,
+    reason: "every building needs a buildable cell",
+};
+
 fn euclidian_distance_sq((x1, y1): Point, (x2, y2): Point) -> usize {
     x1.abs_diff(x2).pow(2) + y1.abs_diff(y2).pow(2)
 }
 
 #[derive(Debug)]
-struct Problem {
+pub struct Problem {
     width: usize,
     buildable: Vec<Row>,
     enemy: Point,
 }
 
 impl Problem {
-    fn new(width: usize, height: usize) -> Self {
+    fn new(width: usize) -> Self {
         Self {
             width,
-            buildable: vec![0; height],
+            buildable: Vec::new(),
             enemy: (usize::MAX, usize::MAX),
         }
     }
 
+    fn add_row(&mut self) {
+        self.buildable.push(0);
+    }
+
     fn can_build(&self, (x, y): Point) -> bool {
         (self.buildable[y] & (1 << x)) != 0
     }
@@ -56,114 +70,271 @@ impl Problem {
     fn fuel_cost(&self, point: Point) -> usize {
         euclidian_distance_sq(point, self.enemy)
     }
+
+    /// Render a layout along with its fuel cost, in the output format.
+    fn render(&self, cost: usize, plants: &[Point], bases: &[Point]) -> String {
+        let mut output = String::new();
+        writeln!(output, "{}", cost).unwrap();
+        for y in 0..self.buildable.len() {
+            for x in 0..self.width {
+                if (x, y) == self.enemy {
+                    write!(output, "M").unwrap();
+                } else if plants.contains(&(x, y)) {
+                    write!(output, "E").unwrap();
+                } else if bases.contains(&(x, y)) {
+                    write!(output, "B").unwrap();
+                } else if self.can_build((x, y)) {
+                    write!(output, ".").unwrap();
+                } else {
+                    write!(output, "X").unwrap();
+                }
+            }
+            writeln!(output).unwrap();
+        }
+        output.pop(); // remove last \n
+
+        output
+    }
 }
 
-#[inline]
-pub fn solve() -> impl Display {
-    let mut lines = include_str!("input.txt").lines();
-
-    let test_cases: usize = lines.next().unwrap().parse().unwrap();
-    cases(
-        (0..test_cases )
-            .map(|_| {
-                let (height, width, buildings): (usize, usize, usize) = {
-                    let mut parts = lines.next().unwrap().split_whitespace();
-                    (
-                        parts.next().unwrap().parse().unwrap(),
-                        parts.next().unwrap().parse().unwrap(),
-                        parts.next().unwrap().parse().unwrap(),
-                    )
-                };
-
-                let mut problem = Problem::new(width, height);
-                for (y, line) in lines.by_ref().take(height).enumerate() {
-                    for (x, c) in line.chars().enumerate() {
-                        match c {
-                            '.' => problem.set_buildable((x, y)),
-                            'M' => problem.set_enemy((x, y)),
-                            _ => {}
+pub struct Solution;
+
+impl common::Problem for Solution {
+    const LETTER: char = 'f';
+    const INPUT: &'static str = include_str!("input.txt");
+    const LIMITS: &'static [Limit] = &[WIDTH, BUILDINGS];
+    const PARALLEL: bool = true;
+
+    type Input = Vec<(Problem, usize)>;
+    type Case = (Problem, usize);
+    type Output = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).counted(|scanner| {
+            let (height, width, buildings) = scanner.tuple::<(usize, usize, usize)>()?;
+
+            // Grown a row at a time rather than sized up front, so that a huge height in a
+            // malformed input runs out of rows instead of memory.
+            let mut problem = Problem::new(width);
+            let mut enemies = 0;
+            for y in 0..height {
+                // One row at a time, so errors point at the right line.
+                let row = scanner.grid(1, width)?[0];
+                problem.add_row();
+                for (x, c) in row.iter().enumerate() {
+                    match c {
+                        b'.' if x < Row::BITS as usize => problem.set_buildable((x, y)),
+                        // Too wide to represent; `validate_case` rejects the whole grid.
+                        b'.' | b'X' => {}
+                        b'M' if enemies == 0 => {
+                            problem.set_enemy((x, y));
+                            enemies += 1;
+                        }
+                        b'M' => return Err(scanner.error_at(x, "more than one enemy base")),
+                        _ => {
+                            return Err(scanner.error_at(x, "cells may only be '.', 'X' or 'M'"));
                         }
                     }
                 }
+            }
+            if enemies == 0 {
+                return Err(scanner.error("no enemy base in the grid"));
+            }
 
-                (problem, buildings)
-            })
-            .collect::<Vec<_>>()
-            .into_par_iter()
-            .map(|(problem, buildings)| {
-                let mut candidate_points: Vec<Point> = problem.buildable_points().collect();
-                candidate_points.sort_by_key(|&point| Reverse(problem.fuel_cost(point)));
-                candidate_points.truncate(buildings);
-
-                let candidate_costs: Vec<usize> = candidate_points.iter()
-                        .map(|&p| problem.fuel_cost(p))
-                        .collect();
-
-                let total = candidate_costs.iter().sum::<usize>();
-                let target = total / 2;
-                
-                // subset sum dp
-                let mut dp = vec![vec![false; target + 1]; buildings + 1];
-                let mut parent = vec![vec![None; target + 1]; buildings + 1];
-                dp[0][0] = true;
-
-                for i in 1..=buildings {
-                    let a = candidate_costs[i-1];
-                    for s in 0..=target {
-                        if dp[i - 1][s] {
-                            dp[i][s] = true;
-                            parent[i][s] = Some(s);
-                        } 
-                        if s >= a && dp[i - 1][s - a] {
-                            dp[i][s] = true;
-                            parent[i][s] = Some(s-a);
-                        }
-                    }
+            Ok((problem, buildings))
+        })
+    }
+
+    fn validate_case(
+        _input: &Self::Input,
+        (problem, buildings): &(Problem, usize),
+    ) -> Result<(), LimitError> {
+        WIDTH.check(problem.width)?;
+        BUILDINGS.check_at_most(*buildings, problem.buildable_points().count() as u64)
+    }
+
+    fn solve_case(_input: &Self::Input, &(ref problem, buildings): &(Problem, usize)) -> String {
+        let mut candidate_points: Vec<Point> = problem.buildable_points().collect();
+        candidate_points.sort_by_key(|&point| Reverse(problem.fuel_cost(point)));
+        candidate_points.truncate(buildings);
+
+        let candidate_costs: Vec<usize> = candidate_points
+            .iter()
+            .map(|&p| problem.fuel_cost(p))
+            .collect();
+
+        let total = candidate_costs.iter().sum::<usize>();
+        let target = total / 2;
+
+        // subset sum dp
+        let mut dp = vec![vec![false; target + 1]; buildings + 1];
+        let mut parent = vec![vec![None; target + 1]; buildings + 1];
+        dp[0][0] = true;
+
+        for i in 1..=buildings {
+            let a = candidate_costs[i - 1];
+            for s in 0..=target {
+                if dp[i - 1][s] {
+                    dp[i][s] = true;
+                    parent[i][s] = Some(s);
+                }
+                if s >= a && dp[i - 1][s - a] {
+                    dp[i][s] = true;
+                    parent[i][s] = Some(s - a);
                 }
+            }
+        }
 
-                let best = (0..=target).rev().find(|&s| dp[buildings][s]).unwrap();
+        let best = (0..=target).rev().find(|&s| dp[buildings][s]).unwrap();
 
-                let mut plants = Vec::new();
-                let mut i = buildings;
-                let mut current = best;
-                loop {
-                    let Some(next) = parent[i][current] else { break; };
-                    if next != current {
-                        plants.push(candidate_points[i - 1]);
-                    }
-                    current = next;
-                    if i == 0 {
-                        break;
-                    }
-                    i -= 1;
+        let mut plants = Vec::new();
+        let mut i = buildings;
+        let mut current = best;
+        while let Some(next) = parent[i][current] {
+            if next != current {
+                plants.push(candidate_points[i - 1]);
+            }
+            current = next;
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+
+        let bases: Vec<(usize, usize)> = candidate_points
+            .iter()
+            .filter(|&p| !plants.contains(p))
+            .copied()
+            .collect();
+
+        problem.render(best, &plants, &bases)
+    }
+
+    fn case_size(_input: &Self::Input, (problem, _): &(Problem, usize)) -> Option<usize> {
+        Some(problem.buildable.len() * problem.width)
+    }
+
+    fn format_case(output: &String) -> String {
+        output.clone()
+    }
+
+    fn judge_case(
+        _input: &Self::Input,
+        (problem, buildings): &(Problem, usize),
+        expected: Option<&str>,
+        found: &str,
+    ) -> Verdict {
+        verdict(judge(problem, *buildings, expected, found))
+    }
+}
+
+impl Reference for Solution {
+    /// Try every way of leaving each buildable point empty or building a power plant or a base on
+    /// it, keeping the layout whose power plants cost the most without costing more than the bases.
+    fn reference_case(_input: &Self::Input, (problem, buildings): &(Problem, usize)) -> String {
+        let points: Vec<Point> = problem.buildable_points().collect();
+
+        let mut best: Option<(usize, Vec<Point>, Vec<Point>)> = None;
+        for mut code in 0..3usize.pow(points.len() as u32) {
+            let (mut plants, mut bases) = (Vec::new(), Vec::new());
+            for &point in &points {
+                match code % 3 {
+                    1 => plants.push(point),
+                    2 => bases.push(point),
+                    _ => {}
                 }
+                code /= 3;
+            }
+            if plants.len() + bases.len() != *buildings {
+                continue;
+            }
 
-                let bases: Vec<(usize, usize)> = candidate_points.iter().filter(|&p| !plants.contains(p))
-                    .copied()
-                    .collect();
-
-                let mut output = String::new();
-                writeln!(output, "{}", best).unwrap();
-                for y in 0..problem.buildable.len() {
-                    for x in 0..problem.width {
-                        if (x, y) == problem.enemy {
-                            write!(output, "M").unwrap();
-                        } else if plants.contains(&(x, y)) {
-                            write!(output, "E").unwrap();
-                        } else if bases.contains(&(x, y)) {
-                            write!(output, "B").unwrap();
-                        } else if problem.can_build((x, y)) {
-                            write!(output, ".").unwrap();
-                        } else {
-                            write!(output, "X").unwrap();
-                        }
-                    }
-                    writeln!(output).unwrap();
+            let cost = |points: &[Point]| points.iter().map(|&p| problem.fuel_cost(p)).sum();
+            let (plant_cost, base_cost): (usize, usize) = (cost(&plants), cost(&bases));
+            if plant_cost <= base_cost && best.as_ref().is_none_or(|&(best, ..)| plant_cost > best)
+            {
+                best = Some((plant_cost, plants, bases));
+            }
+        }
+
+        let (cost, plants, bases) = best.expect("more buildings than buildable points");
+        problem.render(cost, &plants, &bases)
+    }
+}
+
+/// Any layout is accepted as long as it only builds on buildable land, places every building,
+/// doesn't make the power plants cost more than the bases and gives the power plants the fuel cost
+/// it claims, which must match the expected one.
+fn judge(
+    problem: &Problem,
+    buildings: usize,
+    expected: Option<&str>,
+    found: &str,
+) -> Result<(), Verdict> {
+    let height = problem.buildable.len();
+    let parse = |answer| {
+        parse_answer(answer, |scanner| {
+            Ok((
+                scanner.read::<usize>()?,
+                scanner.grid(height, problem.width)?,
+            ))
+        })
+    };
+    let expected = expected.ok_or(Verdict::UnexpectedCase)?;
+    let (expected_cost, _) = parse(expected)?;
+    let (cost, grid) = parse(found)?;
+
+    let mut built = 0;
+    let (mut plant_cost, mut base_cost) = (0, 0);
+    for (y, row) in grid.into_iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            let point = (x, y);
+            let allowed: &[u8] = if point == problem.enemy {
+                b"M"
+            } else if problem.can_build(point) {
+                b".EB"
+            } else {
+                b"X"
+            };
+            if !allowed.contains(&c) {
+                return Err(reject(format!(
+                    "{:?} at row {}, column {} should be one of {:?}",
+                    c as char,
+                    y + 1,
+                    x + 1,
+                    std::str::from_utf8(allowed).unwrap()
+                )));
+            }
+
+            match c {
+                b'E' => {
+                    built += 1;
+                    plant_cost += problem.fuel_cost(point);
                 }
-                output.pop(); // remove last \n
+                b'B' => {
+                    built += 1;
+                    base_cost += problem.fuel_cost(point);
+                }
+                _ => {}
+            }
+        }
+    }
 
-                output
-            })
-            .collect::<Vec<_>>(),
-    )
+    if built != buildings {
+        return Err(reject(format!(
+            "{built} buildings placed, expected {buildings}"
+        )));
+    }
+    if plant_cost > base_cost {
+        return Err(reject(format!(
+            "the power plants cost {plant_cost}, more than the bases' {base_cost}"
+        )));
+    }
+    if plant_cost != cost {
+        return Err(reject(format!(
+            "claims a fuel cost of {cost}, but the power plants cost {plant_cost}"
+        )));
+    }
+
+    check_optimal("fuel cost", expected_cost, cost)
 }
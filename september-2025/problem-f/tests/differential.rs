@@ -0,0 +1,45 @@
+use common::Generator;
+use september_2025_problem_f::Solution;
+
+/// Every grid of the given size with one enemy and any mix of buildable and blocked land, with
+/// every number of buildings that fits.
+fn grids(height: usize, width: usize) -> impl Iterator<Item = String> {
+    let cells = height * width;
+    (0..cells).flat_map(move |enemy| {
+        (0..1u32 << (cells - 1)).flat_map(move |blocked| {
+            let mut grid = String::new();
+            let mut others = 0;
+            let mut buildable = 0;
+            for cell in 0..cells {
+                if cell == enemy {
+                    grid.push('M');
+                } else {
+                    if blocked & (1 << others) != 0 {
+                        grid.push('X');
+                    } else {
+                        grid.push('.');
+                        buildable += 1;
+                    }
+                    others += 1;
+                }
+                if cell % width == width - 1 {
+                    grid.push('\n');
+                }
+            }
+            (1..=buildable).map(move |buildings| format!("1\n{height} {width} {buildings}\n{grid}"))
+        })
+    })
+}
+
+#[test]
+fn differential() {
+    common::assert_differential::<Solution, _>(grids(2, 3).chain(grids(3, 3)));
+}
+
+#[test]
+fn differential_generated() {
+    let constraints = [("max_height", "3"), ("max_width", "4"), ("cases", "20")];
+    common::assert_differential::<Solution, _>(
+        (0..10).map(|seed| Solution.generate_input(seed, &constraints).unwrap()),
+    );
+}
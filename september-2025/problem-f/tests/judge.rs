@@ -0,0 +1,20 @@
+use common::{Problem, Verdict};
+use september_2025_problem_f::Solution;
+
+const INPUT: &str = "1\n3 3 3\nM..\n...\n...";
+const EXPECTED: &str = "Case #1: 8\nM..\n..B\n.BE";
+
+fn verdict(found: &str) -> Verdict {
+    let report = Solution::check(INPUT, EXPECTED, found).unwrap();
+    report.cases[0].1.clone()
+}
+
+#[test]
+fn other_layouts_are_accepted() {
+    assert!(verdict("Case #1: 8\nM.B\n...\nB.E").is_accepted());
+}
+
+#[test]
+fn plants_may_not_cost_more_than_bases() {
+    assert!(!verdict("Case #1: 8\nMB.\nB..\n..E").is_accepted());
+}
@@ -0,0 +1,38 @@
+use common::{InputError, Problem, ScanErrorKind};
+use september_2025_problem_f::Solution;
+
+#[test]
+fn unknown_cell() {
+    let Err(err) = Solution::parse("1\n2 2 1\nM.\n.?") else {
+        panic!("input should not parse");
+    };
+    assert_eq!((err.case, err.line, err.column), (Some(1), 4, 2));
+    assert!(matches!(err.kind, ScanErrorKind::Invalid(_)));
+}
+
+#[test]
+fn enemy_count() {
+    assert!(Solution::parse("1\n1 2 1\n..").is_err());
+    assert!(Solution::parse("1\n1 2 1\nMM").is_err());
+}
+
+#[test]
+fn too_wide() {
+    let input = format!("2\n1 2 1\nM.\n1 70 1\nM{}", ".".repeat(69));
+    let Err(InputError::Unsupported(err)) = Solution::parse_valid(&input) else {
+        panic!("input should be unsupported");
+    };
+    assert_eq!(
+        (err.case, err.limit.what, err.found),
+        (Some(2), "grid width", 70)
+    );
+}
+
+#[test]
+fn huge_height() {
+    let Err(err) = Solution::parse("1\n100000000000000000 3 1\n.M.\n") else {
+        panic!("input should not parse");
+    };
+    assert_eq!(err.case, Some(1));
+    assert!(matches!(err.kind, ScanErrorKind::UnexpectedEof { .. }));
+}
@@ -0,0 +1,37 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct PermutationConstraints {
+        cases: usize = 20,
+        min_n: u8 = 2,
+        /// The solver keeps the used numbers in a `u128`.
+        max_n: u8 = 100,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = PermutationConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &PermutationConstraints) -> String {
+        (0..constraints.cases)
+            .map(|_| {
+                rng.random_range(constraints.min_n..=constraints.max_n)
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Shrink for Solution {
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_lines(input)
+    }
+
+    fn failure(input: &str) -> Option<Failure> {
+        differential_failure::<Self>(input)
+    }
+}
@@ -1,45 +1,128 @@
-use std::fmt::Display;
-
+use itertools::Itertools;
 use rayon::prelude::*;
 
 use common::*;
 
-#[inline]
-pub fn solve() -> impl Display {
-    cases(
-        include_str!("input.txt")
-            .par_lines()
-            .map(|line| {
-                let n: u8 = line.parse().unwrap();
-
-                let (best_d, perm) = (1..n)
-                    .into_par_iter()
-                    .find_map_last(|best_d| {
-                        let perm = (1..=n)
-                            .find_map(|m| {
-                                let mut perm = vec![m.to_string()];
-                                let mut used: u128 = 1 << m;
-                                let mut last = m;
-
-                                for _ in 1..n {
-                                    let next = (1..=n).find(|&x| {
-                                        used & (1 << x) == 0 && last.abs_diff(x) >= best_d
-                                    })?;
-                                    perm.push(next.to_string());
-                                    used |= 1 << next;
-                                    last = next;
-                                }
-
-                                Some(perm)
-                            })?
-                            .join(" ");
-
-                        Some((best_d, perm))
-                    })
-                    .unwrap();
-
-                format!("{best_d}\n{perm}")
+mod generate;
+
+const N: Limit = Limit {
+    what: "n",
+    min: 2,
+    max: u128::BITS as u64 - 1,
+    reason: "used numbers are kept in a u128",
+};
+
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'g';
+    const INPUT: &'static str = include_str!("input.txt");
+    const LIMITS: &'static [Limit] = &[N];
+    const PARALLEL: bool = true;
+
+    type Input = Vec<u8>;
+    type Case = u8;
+    type Output = (u8, Vec<u8>);
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).until_eof(Scanner::read)
+    }
+
+    fn validate_case(_input: &Self::Input, &n: &u8) -> Result<(), LimitError> {
+        N.check(n)
+    }
+
+    fn solve_case(_input: &Self::Input, &n: &u8) -> (u8, Vec<u8>) {
+        (1..n)
+            .into_par_iter()
+            .find_map_last(|best_d| {
+                let perm = (1..=n).find_map(|m| {
+                    let mut perm = vec![m];
+                    let mut used: u128 = 1 << m;
+                    let mut last = m;
+
+                    for _ in 1..n {
+                        let next = (1..=n)
+                            .find(|&x| used & (1 << x) == 0 && last.abs_diff(x) >= best_d)?;
+                        perm.push(next);
+                        used |= 1 << next;
+                        last = next;
+                    }
+
+                    Some(perm)
+                })?;
+
+                Some((best_d, perm))
+            })
+            .unwrap()
+    }
+
+    fn case_size(_input: &Self::Input, &n: &u8) -> Option<usize> {
+        Some(n.into())
+    }
+
+    fn format_case((best_d, perm): &(u8, Vec<u8>)) -> String {
+        format!("{best_d}\n{}", perm.iter().join(" "))
+    }
+
+    fn judge_case(_input: &Self::Input, &n: &u8, expected: Option<&str>, found: &str) -> Verdict {
+        verdict(judge(n, expected, found))
+    }
+}
+
+impl Reference for Solution {
+    /// Try every permutation; only feasible for `n` up to about 10.
+    fn reference_case(_input: &Self::Input, &n: &u8) -> (u8, Vec<u8>) {
+        (1..=n)
+            .permutations(n.into())
+            .map(|perm| {
+                let d = perm
+                    .iter()
+                    .tuple_windows()
+                    .map(|(a, b)| a.abs_diff(*b))
+                    .min()
+                    .unwrap_or(0);
+                (d, perm)
             })
-            .collect::<Vec<_>>(),
-    )
+            .max_by_key(|&(d, _)| d)
+            .unwrap()
+    }
+}
+
+fn parse_permutation(n: u8, answer: &str) -> Result<(u8, Vec<u8>), Verdict> {
+    parse_answer(answer, |scanner| {
+        Ok((scanner.read()?, scanner.vec(n.into())?))
+    })
+}
+
+/// Any permutation of `1..=n` is accepted as long as its smallest gap between neighbours is the
+/// one it claims and as large as the expected one.
+fn judge(n: u8, expected: Option<&str>, found: &str) -> Result<(), Verdict> {
+    let expected = expected.ok_or(Verdict::UnexpectedCase)?;
+    let (expected_d, _) = parse_permutation(n, expected)?;
+    let (d, perm) = parse_permutation(n, found)?;
+
+    let mut seen: u128 = 0;
+    for &x in &perm {
+        if !(1..=n).contains(&x) || seen & (1 << x) != 0 {
+            return Err(reject(format!(
+                "not a permutation of 1..={n}: {x} is out of range or repeated"
+            )));
+        }
+        seen |= 1 << x;
+    }
+
+    let actual_d = perm
+        .iter()
+        .tuple_windows()
+        .map(|(a, b)| a.abs_diff(*b))
+        .min()
+        .unwrap_or(0);
+    if actual_d != d {
+        return Err(reject(format!(
+            "claims a minimum difference of {d}, but it is {actual_d}"
+        )));
+    }
+
+    check_optimal("minimum difference", expected_d, d)
 }
@@ -0,0 +1,6 @@
+use september_2025_problem_g::Solution;
+
+#[test]
+fn differential() {
+    common::assert_differential::<Solution, _>((2..=8).map(|n| n.to_string()));
+}
@@ -0,0 +1,74 @@
+use common::rand::Rng as _;
+use common::rand::seq::IndexedRandom;
+use common::*;
+
+use super::{HOME, Solution};
+
+/// The most tickets the reference solver is quick enough for.
+const REFERENCE_MAX_TICKETS: usize = 12;
+
+constraints! {
+    pub struct TicketConstraints {
+        cases: usize = 21,
+        min_tickets: usize = 1,
+        /// Used tickets are kept in a `u128`, but the search slows down long before that.
+        max_tickets: usize = 12,
+        /// Cities to draw ticket endpoints from, home included.
+        cities: usize = 8,
+        max_name_len: usize = 10,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = TicketConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &TicketConstraints) -> String {
+        const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        counted_input((0..constraints.cases).map(|_| {
+            let mut cities = vec![HOME.to_owned()];
+            while cities.len() < constraints.cities.max(2) {
+                let len = rng.random_range(1..=constraints.max_name_len);
+                let city = random_word(rng, LETTERS, len);
+                if !cities.contains(&city) {
+                    cities.push(city);
+                }
+            }
+
+            let count = rng.random_range(constraints.min_tickets..=constraints.max_tickets);
+            let mut case = count.to_string();
+            for _ in 0..count {
+                let pair: Vec<_> = cities.choose_multiple(rng, 2).collect();
+                case.push_str(&format!("\n{} {}", pair[0], pair[1]));
+            }
+            case
+        }))
+    }
+}
+
+impl Shrink for Solution {
+    /// Remove cases, then tickets.
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_counted(
+            input,
+            |count| Some(1 + count.trim().parse::<usize>().ok()?),
+            |case| {
+                removals(&case[1..])
+                    .iter()
+                    .map(|tickets| format!("{}\n{}", tickets.len(), tickets.join("\n")))
+                    .collect()
+            },
+        )
+    }
+
+    fn failure(input: &str) -> Option<Failure> {
+        // The reference tries every set of used tickets, so only short lists can be checked with it.
+        let small = Self::parse(input)
+            .is_ok_and(|cases| cases.iter().all(|case| case.len() <= REFERENCE_MAX_TICKETS));
+        if small {
+            differential_failure::<Self>(input)
+        } else {
+            panic_failure::<Self>(input)
+        }
+    }
+}
@@ -1,11 +1,27 @@
-use std::fmt::{Display, Write};
+use std::collections::VecDeque;
+use std::fmt::Write;
 
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use common::*;
 
+mod generate;
+
 const HOME: &str = "BATUMI";
 
+const TICKETS: Limit = Limit {
+    what: "ticket count",
+    min: 0,
+    max: u128::BITS as u64,
+    reason: "used tickets are kept in a u128",
+};
+const CITIES: Limit = Limit {
+    what: "city count",
+    min: 1,
+    max: u8::MAX as u64,
+    reason: "cities are numbered with u8s",
+};
+
 #[derive(Debug, Clone)]
 struct State {
     used_tickets: u128,
@@ -39,42 +55,47 @@ impl State {
     }
 }
 
-struct Searcher {
-    nodes: Vec<&'static str>,
+type CandidateCache = HashMap<u8, Vec<(u8, (u8, u8))>>;
+
+struct Searcher<'a> {
+    nodes: Vec<&'a str>,
     best_bought_count: usize,
     best_bought: Vec<(u8, u8)>,
 
+    tickets: Vec<(u8, u8)>,
     ticket_count: u8,
     home: u8,
     state: State,
     seen: HashMap<(u8, u8), u8>,
+    countdown: Countdown,
 }
 
-impl Searcher {
-    fn new(
-        tickets: &[(&'static str, &'static str)],
-    ) -> (Self, HashMap<u8, Vec<(u8, (u8, u8))>>) {
+impl<'a> Searcher<'a> {
+    fn new(tickets: &'a [(String, String)]) -> (Self, CandidateCache) {
         let mut nodes = HashSet::default();
         nodes.insert(HOME);
 
         for ticket in tickets {
-            nodes.insert(ticket.0);
-            nodes.insert(ticket.1);
+            nodes.insert(ticket.0.as_str());
+            nodes.insert(ticket.1.as_str());
         }
 
-        let mut nodes: Vec<&'static str> = nodes.into_iter().collect();
+        let mut nodes: Vec<&'a str> = nodes.into_iter().collect();
         nodes.sort();
 
-        let node_map: HashMap<&'static str, u8> =
-            nodes.iter().enumerate().map(|(i, n)| (*n, u8::try_from(i).expect("Too many nodes"))).collect();
+        let node_map: HashMap<&'a str, u8> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (*n, u8::try_from(i).expect("Too many nodes")))
+            .collect();
 
         let home = node_map[HOME];
 
         let tickets: Vec<(u8, u8)> = tickets
-            .into_iter()
+            .iter()
             .map(|ticket| {
-                let a = node_map[&ticket.0];
-                let b = node_map[&ticket.1];
+                let a = node_map[ticket.0.as_str()];
+                let b = node_map[ticket.1.as_str()];
                 if a < b { (a, b) } else { (b, a) }
             })
             .collect();
@@ -109,20 +130,27 @@ impl Searcher {
                 best_bought_count: usize::MAX,
                 best_bought: Vec::new(),
                 ticket_count: u8::try_from(tickets.len()).expect("Too many tickets"),
+                tickets,
                 home,
                 state,
                 seen: HashMap::default(),
+                countdown: Countdown::start(),
             },
             candidate_cache,
         )
     }
 
-    fn search(&mut self, candidate_cache: &HashMap<u8, Vec<(u8, (u8, u8))>>) {
+    fn search(&mut self, candidate_cache: &CandidateCache) {
+        // Out of budget, but only give up once there is some route to fall back on.
+        if !self.countdown.tick() && self.best_bought_count != usize::MAX {
+            return;
+        }
+
         if self.state.count() == self.ticket_count {
             let must_return = self.state.position != self.home;
             let bought_count = self.state.bought.len() + if must_return { 1 } else { 0 };
             if bought_count < self.best_bought_count {
-                eprintln!("\tNew best: {}", bought_count);
+                log::trace!("new best: {bought_count} bought tickets");
                 self.best_bought = self.state.bought.clone();
                 if must_return {
                     self.best_bought.push((self.state.position, self.home));
@@ -132,13 +160,26 @@ impl Searcher {
             return;
         }
 
-        let key = (self.state.count() as u8, self.state.position);
+        let key = (self.state.count(), self.state.position);
         if self.seen.get(&key).copied().unwrap_or(u8::MAX) < self.state.bought.len() as u8 {
             return;
         }
         self.seen.insert(key, self.state.bought.len() as u8);
 
-        if self.state.bought.len() >= self.best_bought_count {
+        // No route through the tickets left can beat the best one found.
+        let unused = self
+            .tickets
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| !self.state.has(i as u8))
+            .map(|(_, &(a, b))| (a as usize, b as usize));
+        let fewest = fewest_bought_between(
+            self.nodes.len(),
+            unused,
+            self.state.position as usize,
+            self.home as usize,
+        );
+        if self.state.bought.len() + fewest >= self.best_bought_count {
             return;
         }
 
@@ -157,9 +198,10 @@ impl Searcher {
             let prev_position = self.state.position;
 
             if self.state.position != ticket.0 && self.state.position != ticket.1 {
-                for &destination in &[ticket.0, ticket.1] {
-                    self.state.bought.push((self.state.position, destination));
-                    self.state.position = destination;
+                // Buy a ticket to one end of it, then use it to get to the other.
+                for &(start, end) in &[ticket, (ticket.1, ticket.0)] {
+                    self.state.bought.push((prev_position, start));
+                    self.state.position = end;
                     self.search(candidate_cache);
                     self.state.bought.pop();
                 }
@@ -178,35 +220,289 @@ impl Searcher {
     }
 }
 
-#[inline]
-pub fn solve() -> impl Display {
-    let data = include_str!("input.txt");
-    let mut lines = data.lines();
-    let num_cases: usize = lines.next().unwrap().parse().unwrap();
+pub struct Solution;
 
-    cases((0..num_cases).map(|_| {
-        let ticket_count: usize = lines.next().unwrap().parse().unwrap();
-        let mut result = String::new();
+impl Problem for Solution {
+    const LETTER: char = 'h';
+    const INPUT: &'static str = include_str!("input.txt");
+    const LIMITS: &'static [Limit] = &[TICKETS, CITIES];
 
-        let mut tickets = Vec::new();
-        for _ in 0..ticket_count {
-            let line = lines.next().unwrap();
-            let ticket = line.split_once(' ').unwrap();
-            tickets.push(ticket);
-        }
+    type Input = Vec<Vec<(String, String)>>;
+    type Case = Vec<(String, String)>;
+    type Output = Searched<Vec<(String, String)>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).counted(|scanner| {
+            scanner
+                .counted(|scanner| Ok((scanner.token()?.to_owned(), scanner.token()?.to_owned())))
+        })
+    }
+
+    fn validate_case(
+        _input: &Self::Input,
+        tickets: &Vec<(String, String)>,
+    ) -> Result<(), LimitError> {
+        TICKETS.check(tickets.len())?;
+        let cities: HashSet<&str> = tickets
+            .iter()
+            .flat_map(|(src, dst)| [src.as_str(), dst.as_str()])
+            .chain([HOME])
+            .collect();
+        CITIES.check(cities.len())
+    }
 
-        eprintln!("Start: {:?}", tickets);
+    fn solve_case(
+        input: &Self::Input,
+        tickets: &Vec<(String, String)>,
+    ) -> Searched<Vec<(String, String)>> {
+        log::trace!("tickets: {tickets:?}");
 
-        let start = std::time::Instant::now();
-        let (mut searcher, candidate_cache) = Searcher::new(&tickets);
+        let (mut searcher, candidate_cache) = Searcher::new(tickets);
         searcher.search(&candidate_cache);
-        eprintln!("Time: {:?}", start.elapsed());
 
-        writeln!(result, "{}", searcher.best_bought_count).unwrap();
-        for &(src, dst) in &searcher.best_bought {
-            writeln!(result, "{} {}", searcher.nodes[src as usize], searcher.nodes[dst as usize]).unwrap();
+        log::debug!(
+            "case #{}: {} tickets, bought {} after {} nodes",
+            case_number(input, tickets),
+            tickets.len(),
+            searcher.best_bought.len(),
+            searcher.countdown.visited()
+        );
+
+        let bought = searcher
+            .best_bought
+            .iter()
+            .map(|&(src, dst)| {
+                (
+                    searcher.nodes[src as usize].to_owned(),
+                    searcher.nodes[dst as usize].to_owned(),
+                )
+            })
+            .collect();
+        searcher.countdown.finish(bought)
+    }
+
+    fn case_size(_input: &Self::Input, tickets: &Vec<(String, String)>) -> Option<usize> {
+        Some(tickets.len())
+    }
+
+    fn optimal(bought: &Searched<Vec<(String, String)>>) -> bool {
+        bought.optimal
+    }
+
+    fn judge_case(
+        _input: &Self::Input,
+        tickets: &Vec<(String, String)>,
+        _expected: Option<&str>,
+        found: &str,
+    ) -> Verdict {
+        verdict(judge(tickets, found))
+    }
+
+    fn format_case(bought: &Searched<Vec<(String, String)>>) -> String {
+        let bought = &bought.best;
+        let mut result = String::new();
+
+        writeln!(result, "{}", bought.len()).unwrap();
+        for (src, dst) in bought {
+            writeln!(result, "{} {}", src, dst).unwrap();
         }
 
         result
-    }))
+    }
+}
+
+impl Reference for Solution {
+    /// Search every way of travelling, breadth first over where we are and which tickets are used:
+    /// using a ticket is free and buying one to any city costs one.
+    fn reference_case(
+        _input: &Self::Input,
+        tickets: &Vec<(String, String)>,
+    ) -> Searched<Vec<(String, String)>> {
+        let mut cities = vec![HOME];
+        for city in tickets.iter().flat_map(|(a, b)| [a.as_str(), b.as_str()]) {
+            if !cities.contains(&city) {
+                cities.push(city);
+            }
+        }
+        let id = |city: &str| cities.iter().position(|&other| other == city).unwrap();
+        let ends: Vec<(usize, usize)> = tickets.iter().map(|(a, b)| (id(a), id(b))).collect();
+
+        // States are numbered `used * cities.len() + position`, where `used` has a bit per used
+        // ticket; each remembers the one it was reached from.
+        let state = |used: usize, position| used * cities.len() + position;
+        let goal = state((1 << tickets.len()) - 1, 0);
+        let mut bought = vec![usize::MAX; state(1 << tickets.len(), 0)];
+        let mut previous = vec![0; bought.len()];
+        let mut queue = VecDeque::from([0]);
+        bought[0] = 0;
+        while let Some(current) = queue.pop_front() {
+            if current == goal {
+                break;
+            }
+            let (used, position) = (current / cities.len(), current % cities.len());
+            let moves = ends
+                .iter()
+                .enumerate()
+                .filter(|&(i, &(a, b))| used & 1 << i == 0 && (a == position || b == position))
+                .map(|(i, &(a, b))| (state(used | 1 << i, a + b - position), 0))
+                .chain((0..cities.len()).map(|city| (state(used, city), 1)));
+            for (next, cost) in moves {
+                if bought[current] + cost < bought[next] {
+                    bought[next] = bought[current] + cost;
+                    previous[next] = current;
+                    if cost == 0 {
+                        queue.push_front(next);
+                    } else {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        // Walk back from home with every ticket used, noting the tickets bought on the way.
+        let mut best = Vec::new();
+        let mut current = goal;
+        while current != 0 {
+            let before = previous[current];
+            if bought[before] < bought[current] {
+                best.push((
+                    cities[before % cities.len()].to_owned(),
+                    cities[current % cities.len()].to_owned(),
+                ));
+            }
+            current = before;
+        }
+        best.reverse();
+        Searched {
+            best,
+            optimal: true,
+        }
+    }
+}
+
+/// The fewest tickets that must be bought to leave home, use every ticket and come back.
+fn fewest_bought(tickets: &[(String, String)]) -> usize {
+    let mut ids: HashMap<&str, usize> = HashMap::default();
+    ids.insert(HOME, 0);
+    let mut id = |city| {
+        let next = ids.len();
+        *ids.entry(city).or_insert(next)
+    };
+    let edges: Vec<(usize, usize)> = tickets
+        .iter()
+        .map(|(a, b)| (id(a.as_str()), id(b.as_str())))
+        .collect();
+    fewest_bought_between(ids.len(), edges, 0, 0)
+}
+
+/// The fewest tickets that must be bought to get from `from` to `to` using every one of
+/// `tickets`, with cities numbered below `cities`.
+///
+/// Such a trip is a round trip once given a free ticket back from `to` to `from`, so every city
+/// must end up visited an even number of times, which takes one bought ticket per two odd cities.
+/// When the tickets fall into several groups unreachable from each other, each group also needs at
+/// least one bought ticket to get in and out of it.
+fn fewest_bought_between(
+    cities: usize,
+    tickets: impl IntoIterator<Item = (usize, usize)>,
+    from: usize,
+    to: usize,
+) -> usize {
+    let mut degree = vec![0usize; cities];
+    let mut parent: Vec<usize> = (0..cities).collect();
+    for (a, b) in tickets.into_iter().chain([(to, from)]) {
+        degree[a] += 1;
+        degree[b] += 1;
+        let (a, b) = (root(&mut parent, a), root(&mut parent, b));
+        parent[a] = b;
+    }
+
+    // Odd cities per group.
+    let mut odd: HashMap<usize, usize> = HashMap::default();
+    for (city, &degree) in degree.iter().enumerate() {
+        if degree > 0 {
+            *odd.entry(root(&mut parent, city)).or_default() += degree % 2;
+        }
+    }
+
+    if odd.len() == 1 {
+        odd.into_values().sum::<usize>() / 2
+    } else {
+        odd.into_values().map(|odd| (odd / 2).max(1)).sum()
+    }
+}
+
+/// Any list of bought tickets between known cities is accepted as long as it is as short as
+/// possible and, together with the given tickets, makes a round trip from home possible: every
+/// city is visited an even number of times and every city with a ticket can be reached from home.
+fn judge(tickets: &[(String, String)], found: &str) -> Result<(), Verdict> {
+    let bought = parse_answer(found, |scanner| {
+        scanner.counted(|scanner| Ok((scanner.token()?, scanner.token()?)))
+    })?;
+
+    let known = |city: &str| city == HOME || tickets.iter().any(|(a, b)| a == city || b == city);
+    if let Some(city) = bought
+        .iter()
+        .flat_map(|&(a, b)| [a, b])
+        .find(|&city| !known(city))
+    {
+        return Err(reject(format!("{city} is not on any ticket")));
+    }
+
+    let mut ids: HashMap<&str, usize> = HashMap::default();
+    ids.insert(HOME, 0);
+    let mut id = |city| {
+        let next = ids.len();
+        *ids.entry(city).or_insert(next)
+    };
+    let edges: Vec<(usize, usize)> = tickets
+        .iter()
+        .map(|(a, b)| (a.as_str(), b.as_str()))
+        .chain(bought.iter().copied())
+        .map(|(a, b)| (id(a), id(b)))
+        .collect();
+    let names: Vec<&str> = {
+        let mut names = vec![""; ids.len()];
+        for (&city, &id) in &ids {
+            names[id] = city;
+        }
+        names
+    };
+
+    let mut degree = vec![0usize; names.len()];
+    let mut parent: Vec<usize> = (0..names.len()).collect();
+    for &(a, b) in &edges {
+        degree[a] += 1;
+        degree[b] += 1;
+        let (a, b) = (root(&mut parent, a), root(&mut parent, b));
+        parent[a] = b;
+    }
+    if let Some(city) = (0..names.len()).find(|&city| degree[city] % 2 == 1) {
+        return Err(reject(format!(
+            "{} is visited an odd number of times",
+            names[city]
+        )));
+    }
+    let home = root(&mut parent, 0);
+    if let Some(city) = (0..names.len()).find(|&city| root(&mut parent, city) != home) {
+        return Err(reject(format!(
+            "{} cannot be reached from {HOME}",
+            names[city]
+        )));
+    }
+
+    check_optimal(
+        "number of bought tickets",
+        fewest_bought(tickets),
+        bought.len(),
+    )
+}
+
+/// The representative of `x`'s group in a union-find forest, halving paths on the way.
+fn root(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
 }
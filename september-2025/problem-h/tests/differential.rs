@@ -0,0 +1,40 @@
+use common::{Generator, counted_input};
+use september_2025_problem_h::Solution;
+
+/// Every list of up to `max` tickets between home and three other cities, as one case each.
+fn ticket_lists(max: usize) -> String {
+    const CITIES: [&str; 4] = ["BATUMI", "A", "B", "C"];
+    let pairs: Vec<String> = (0..CITIES.len())
+        .flat_map(|a| (a + 1..CITIES.len()).map(move |b| format!("{} {}", CITIES[a], CITIES[b])))
+        .collect();
+
+    let mut lists: Vec<Vec<usize>> = vec![Vec::new()];
+    let mut longer = lists.clone();
+    for _ in 0..max {
+        longer = longer
+            .iter()
+            .flat_map(|list| {
+                let first = list.last().copied().unwrap_or(0);
+                (first..pairs.len()).map(move |pair| [list.as_slice(), &[pair]].concat())
+            })
+            .collect();
+        lists.extend(longer.iter().cloned());
+    }
+    counted_input(lists.iter().map(|list| {
+        let tickets: Vec<&str> = list.iter().map(|&pair| pairs[pair].as_str()).collect();
+        format!("{}\n{}", tickets.len(), tickets.join("\n"))
+    }))
+}
+
+#[test]
+fn differential() {
+    common::assert_differential::<Solution, _>([ticket_lists(5)]);
+}
+
+#[test]
+fn differential_generated() {
+    let constraints = [("max_tickets", "7"), ("cities", "5"), ("cases", "20")];
+    common::assert_differential::<Solution, _>(
+        (0..10).map(|seed| Solution.generate_input(seed, &constraints).unwrap()),
+    );
+}
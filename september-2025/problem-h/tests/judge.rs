@@ -0,0 +1,27 @@
+use common::{Problem, Verdict};
+use september_2025_problem_h::Solution;
+
+fn verdicts(found: &str) -> Vec<Verdict> {
+    let input = include_str!("../src/sample_input.txt");
+    let expected = include_str!("../src/sample_output.txt");
+    let report = Solution::check(input, expected, found).unwrap();
+    report
+        .cases
+        .into_iter()
+        .map(|(_, verdict)| verdict)
+        .collect()
+}
+
+#[test]
+fn other_shortest_routes_are_accepted() {
+    let found = "Case #1: 2\nBATUMI ODESSA\nISTANBUL CONSTANTA\n\
+                 Case #2: 3\nBATUMI BURGAS\nVARNA SAMSUN\nTRABZON BATUMI";
+    assert!(verdicts(found).iter().all(Verdict::is_accepted));
+}
+
+#[test]
+fn bought_tickets_must_make_a_route() {
+    let found = "Case #1: 2\nBATUMI BATUMI\nBATUMI BATUMI\n\
+                 Case #2: 3\nBATUMI BATUMI\nBATUMI BATUMI\nBATUMI BATUMI";
+    assert!(verdicts(found).iter().all(|verdict| !verdict.is_accepted()));
+}
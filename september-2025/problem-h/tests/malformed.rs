@@ -0,0 +1,31 @@
+use common::{InputError, Problem};
+use september_2025_problem_h::Solution;
+
+/// One case with `cities` cities, home included, joined by as few tickets as possible.
+fn cities(cities: usize) -> String {
+    let others: Vec<String> = (1..cities).map(|i| format!("C{i}")).collect();
+    let tickets: Vec<String> = others
+        .chunks(2)
+        .map(|pair| {
+            format!(
+                "{} {}",
+                pair[0],
+                pair.get(1).map_or("BATUMI", String::as_str)
+            )
+        })
+        .collect();
+    format!("1\n{}\n{}", tickets.len(), tickets.join("\n"))
+}
+
+#[test]
+fn most_cities() {
+    assert!(Solution::parse_valid(&cities(255)).is_ok());
+
+    let Err(InputError::Unsupported(err)) = Solution::parse_valid(&cities(256)) else {
+        panic!("input should be unsupported");
+    };
+    assert_eq!(
+        (err.case, err.limit.what, err.found),
+        (Some(1), "city count", 256)
+    );
+}
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use common::rand::Rng as _;
+use common::rand::seq::IndexedRandom;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct NetworkConstraints {
+        cases: usize = 18,
+        /// At least two, so there's always someone to suggest.
+        min_users: usize = 2,
+        max_users: usize = 50,
+        max_name_len: usize = 10,
+        min_operations: usize = 1,
+        max_operations: usize = 2000,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = NetworkConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &NetworkConstraints) -> String {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+        counted_input((0..constraints.cases).map(|_| {
+            let n = rng.random_range(constraints.min_users.max(2)..=constraints.max_users);
+            let mut users = Vec::with_capacity(n);
+            while users.len() < n {
+                let len = rng.random_range(1..=constraints.max_name_len);
+                let user = random_word(rng, LETTERS, len);
+                if !users.contains(&user) {
+                    users.push(user);
+                }
+            }
+
+            // Track friendships so users who are already friends with everyone never ask for a
+            // suggestion, which would have no answer.
+            let mut friendships = HashSet::new();
+            let m = rng.random_range(constraints.min_operations..=constraints.max_operations);
+            let mut case = format!("{n} {m}\n{}", users.join("\n"));
+            for _ in 0..m {
+                let pair: Vec<_> = users.choose_multiple(rng, 2).collect();
+                let (alice, bob) = (pair[0], pair[1]);
+                let friends = |alice: &String, friendships: &HashSet<_>| {
+                    users
+                        .iter()
+                        .filter(|&bob| friendships.contains(&(alice, bob)))
+                        .count()
+                };
+                let operation = if rng.random_bool(0.4) && friends(alice, &friendships) < n - 1 {
+                    format!("SUGGEST {alice}")
+                } else if rng.random_bool(0.7) {
+                    friendships.insert((alice, bob));
+                    friendships.insert((bob, alice));
+                    format!("ADD {alice} {bob}")
+                } else {
+                    friendships.remove(&(alice, bob));
+                    friendships.remove(&(bob, alice));
+                    format!("REMOVE {alice} {bob}")
+                };
+                case.push('\n');
+                case.push_str(&operation);
+            }
+            case
+        }))
+    }
+}
+
+impl Shrink for Solution {
+    /// Remove cases, then operations, then users no operation mentions.
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_counted(
+            input,
+            |header| {
+                let (n, m) = header.trim().split_once(' ')?;
+                Some(1 + n.parse::<usize>().ok()? + m.parse::<usize>().ok()?)
+            },
+            |case| {
+                let n = case[0].split_once(' ').unwrap().0.parse().unwrap();
+                let (users, operations) = case[1..].split_at(n);
+                let render = |users: &[&str], operations: &[&str]| {
+                    let mut lines = vec![format!("{} {}", users.len(), operations.len())];
+                    lines.extend(users.iter().chain(operations).map(|&line| line.to_owned()));
+                    lines.join("\n")
+                };
+
+                let mentioned = |user: &&str| {
+                    operations.iter().any(|operation| {
+                        operation
+                            .split_whitespace()
+                            .skip(1)
+                            .any(|name| name == *user)
+                    })
+                };
+                let mut candidates: Vec<String> = removals(operations)
+                    .iter()
+                    .filter(|operations| answerable(users.len(), operations))
+                    .map(|operations| render(users, operations))
+                    .collect();
+                if users.len() > 2 {
+                    let unmentioned: Vec<&str> = users
+                        .iter()
+                        .copied()
+                        .filter(|user| !mentioned(user))
+                        .collect();
+                    for user in unmentioned {
+                        let users: Vec<&str> = users
+                            .iter()
+                            .copied()
+                            .filter(|&other| other != user)
+                            .collect();
+                        candidates.push(render(&users, operations));
+                    }
+                }
+                candidates
+            },
+        )
+    }
+}
+
+/// Whether every suggestion has someone to suggest, i.e. nobody asks for one while already being
+/// friends with all `users` others.
+fn answerable(users: usize, operations: &[&str]) -> bool {
+    let mut friendships = HashSet::new();
+    operations.iter().all(|operation| {
+        match operation.split_whitespace().collect::<Vec<_>>()[..] {
+            ["ADD", alice, bob] => {
+                friendships.insert((alice, bob));
+                friendships.insert((bob, alice));
+            }
+            ["REMOVE", alice, bob] => {
+                friendships.remove(&(alice, bob));
+                friendships.remove(&(bob, alice));
+            }
+            ["SUGGEST", alice] => {
+                return friendships
+                    .iter()
+                    .filter(|&&(from, _)| from == alice)
+                    .count()
+                    < users - 1;
+            }
+            _ => {}
+        }
+        true
+    })
+}
@@ -1,68 +1,160 @@
 use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
-use std::fmt::{Display, Write};
+use std::fmt::Write;
 
 use common::*;
 
-#[inline]
-pub fn solve() -> impl Display {
-    let mut lines = include_str!("input.txt").lines();
-    let mut outputs = Vec::new();
-    for _ in 0..lines.next().unwrap().parse::<usize>().unwrap() {
-        let mut output = "\n".to_string();
-        let (n, m) = lines.next().unwrap().split_once(' ').unwrap();
-        let n: usize = n.parse().unwrap();
-        let m: usize = m.parse().unwrap();
-        let usernames: HashSet<_> = lines.by_ref().take(n).collect();
-        let mut friends: HashMap<&str, HashSet<&str>> = HashMap::default();
-        for operation in lines.by_ref().take(m) {
-            let (opcode, args) = operation.split_once(' ').unwrap();
-            match opcode {
-                "ADD" => {
-                    let (alice, bob) = args.split_once(' ').unwrap();
-                    friends.entry(alice).or_default().insert(bob);
-                    friends.entry(bob).or_default().insert(alice);
-                }
-
-                "REMOVE" => {
-                    let (alice, bob) = args.split_once(' ').unwrap();
-                    friends.entry(alice).or_default().remove(bob);
-                    friends.entry(bob).or_default().remove(alice);
-                }
-
-                "SUGGEST" => {
-                    let alice = args;
-                    let Some(friends_of_alice) = friends.get(alice) else {
-                        writeln!(
-                            output,
-                            "{}",
-                            usernames.iter().filter(|&&bob| bob != alice).min().unwrap()
-                        )
-                        .unwrap();
-                        continue;
-                    };
-                    let candidates = usernames
-                        .difference(friends_of_alice)
-                        .copied()
-                        .filter(|&x| x != alice);
-                    let new_friend = candidates
-                        .max_by_key(|&bob| {
-                            let Some(friends_of_bob) = friends.get(bob) else {
-                                return (0, Reverse(bob));
-                            };
-                            (
-                                friends_of_alice.intersection(friends_of_bob).count(),
-                                Reverse(bob),
-                            )
-                        })
-                        .unwrap();
-                    writeln!(output, "{}", new_friend).unwrap();
-                }
-
-                _ => unreachable!("unknown opcode {opcode:?}"),
+mod generate;
+
+pub enum Operation {
+    Add(String, String),
+    Remove(String, String),
+    Suggest(String),
+}
+
+pub struct Case {
+    usernames: Vec<String>,
+    operations: Vec<Operation>,
+}
+
+const USERS: Limit = Limit {
+    what: "user count",
+    min: 2,
+    max: u64::MAX,
+    reason: "suggestions need someone other than the user asking",
+};
+const FRIENDS: Limit = Limit {
+    what: "friend count of a user asking for a suggestion",
+    min: 0,
+    max: u64::MAX,
+    reason: "suggestions need someone who isn't a friend yet",
+};
+
+type Friends<'a> = HashMap<&'a str, HashSet<&'a str>>;
+
+/// Apply a case's operations in order, calling `suggest` with the user asking and everyone's
+/// friends at the time for each suggestion.
+fn replay<'a>(case: &'a Case, mut suggest: impl FnMut(&'a str, &Friends<'a>)) {
+    let mut friends = Friends::default();
+    for operation in &case.operations {
+        match operation {
+            Operation::Add(alice, bob) => {
+                friends.entry(alice).or_default().insert(bob);
+                friends.entry(bob).or_default().insert(alice);
+            }
+
+            Operation::Remove(alice, bob) => {
+                friends.entry(alice).or_default().remove(bob.as_str());
+                friends.entry(bob).or_default().remove(alice.as_str());
             }
+
+            Operation::Suggest(alice) => suggest(alice, &friends),
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'i';
+    const INPUT: &'static str = include_str!("input.txt");
+    const LIMITS: &'static [Limit] = &[USERS, FRIENDS];
+
+    type Input = Vec<Case>;
+    type Case = Case;
+    type Output = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).counted(|scanner| {
+            let (n, m) = scanner.tuple::<(usize, usize)>()?;
+            let usernames = scanner.lines(n)?.into_iter().map(str::to_owned).collect();
+            let operations = (0..m)
+                .map(|_| {
+                    let opcode = scanner.token()?;
+                    let mut name = || scanner.token().map(str::to_owned);
+                    Ok(match opcode {
+                        "ADD" => Operation::Add(name()?, name()?),
+                        "REMOVE" => Operation::Remove(name()?, name()?),
+                        "SUGGEST" => Operation::Suggest(name()?),
+                        _ => {
+                            return Err(scanner.error(format!(
+                                "unknown operation {opcode:?}, expected ADD, REMOVE or SUGGEST"
+                            )));
+                        }
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+
+            Ok(Case {
+                usernames,
+                operations,
+            })
+        })
+    }
+
+    fn validate_case(_input: &Self::Input, case: &Case) -> Result<(), LimitError> {
+        let usernames: HashSet<&str> = case.usernames.iter().map(String::as_str).collect();
+        USERS.check(usernames.len())?;
+        let mut result = Ok(());
+        replay(case, |alice, friends| {
+            let others = usernames.iter().filter(|&&bob| bob != alice);
+            let friends_of_alice = friends.get(alice);
+            let befriended = others
+                .clone()
+                .filter(|bob| friends_of_alice.is_some_and(|friends| friends.contains(*bob)))
+                .count();
+            if result.is_ok() {
+                result = FRIENDS.check_at_most(befriended, others.count() as u64 - 1);
+            }
+        });
+        result
+    }
+
+    fn solve_case(_input: &Self::Input, case: &Case) -> Vec<String> {
+        let mut suggestions = Vec::new();
+        let usernames: HashSet<&str> = case.usernames.iter().map(String::as_str).collect();
+        replay(case, |alice, friends| {
+            let Some(friends_of_alice) = friends.get(alice) else {
+                suggestions.push(
+                    usernames
+                        .iter()
+                        .filter(|&&bob| bob != alice)
+                        .min()
+                        .unwrap()
+                        .to_string(),
+                );
+                return;
+            };
+            let candidates = usernames
+                .difference(friends_of_alice)
+                .copied()
+                .filter(|&x| x != alice);
+            // [`FRIENDS`] leaves someone to suggest.
+            let new_friend = candidates
+                .max_by_key(|&bob| {
+                    let Some(friends_of_bob) = friends.get(bob) else {
+                        return (0, Reverse(bob));
+                    };
+                    (
+                        friends_of_alice.intersection(friends_of_bob).count(),
+                        Reverse(bob),
+                    )
+                })
+                .unwrap();
+            suggestions.push(new_friend.to_string());
+        });
+        suggestions
+    }
+
+    fn case_size(_input: &Self::Input, case: &Case) -> Option<usize> {
+        Some(case.usernames.len() + case.operations.len())
+    }
+
+    fn format_case(suggestions: &Vec<String>) -> String {
+        let mut output = "\n".to_string();
+        for suggestion in suggestions {
+            writeln!(output, "{}", suggestion).unwrap();
         }
-        outputs.push(output);
+        output
     }
-    cases(outputs)
 }
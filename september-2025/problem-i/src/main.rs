@@ -1,4 +1,3 @@
 fn main() {
-    let output = problem_i::solve();
-    println!("{output}");
+    common::main(&september_2025_problem_i::Solution);
 }
@@ -0,0 +1,28 @@
+use common::{InputError, Problem, ScanErrorKind};
+use september_2025_problem_i::Solution;
+
+#[test]
+fn unknown_operation() {
+    let Err(err) = Solution::parse("1\n2 2\nal\nbo\nADD al bo\nPOKE al") else {
+        panic!("input should not parse");
+    };
+    assert_eq!((err.case, err.line, err.column), (Some(1), 6, 1));
+    assert!(matches!(err.kind, ScanErrorKind::Invalid(_)));
+}
+
+#[test]
+fn friends_with_everyone() {
+    let input = "1\n3 3\nal\nbo\ncy\nADD al bo\nADD al cy\nSUGGEST al";
+    let Err(InputError::Unsupported(err)) = Solution::parse_valid(input) else {
+        panic!("input should be rejected");
+    };
+    assert_eq!(
+        (err.case, err.limit.what),
+        (Some(1), "friend count of a user asking for a suggestion")
+    );
+    assert_eq!((err.found, err.max), (2, 1));
+
+    // Someone unfriended can be suggested again.
+    let input = "1\n3 4\nal\nbo\ncy\nADD al bo\nADD al cy\nREMOVE bo al\nSUGGEST al";
+    assert_eq!(Solution::run(input).unwrap().trim(), "Case #1: \nbo");
+}
@@ -0,0 +1,92 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct VenueConstraints {
+        cases: usize = 30,
+        /// Organizers, sockets and cables are kept in `u16` masks, so each is at most 16, but the
+        /// search takes seconds per case on random venues from 8 onwards.
+        max_organizers: usize = 7,
+        max_sockets: usize = 7,
+        max_cables: usize = 7,
+        max_coordinate: usize = 500,
+        max_cable_length: usize = 850,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = VenueConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &VenueConstraints) -> String {
+        counted_input((0..constraints.cases).map(|_| {
+            let organizers = rng.random_range(1..=constraints.max_organizers);
+            let sockets = rng.random_range(1..=constraints.max_sockets);
+            let cables = rng.random_range(1..=constraints.max_cables);
+
+            let lengths: Vec<_> = (0..cables)
+                .map(|_| {
+                    rng.random_range(1..=constraints.max_cable_length)
+                        .to_string()
+                })
+                .collect();
+            let mut case = format!("{organizers} {sockets}\n{}", lengths.join(" "));
+            for _ in 0..organizers + sockets {
+                let x = rng.random_range(0..=constraints.max_coordinate);
+                let y = rng.random_range(0..=constraints.max_coordinate);
+                case.push_str(&format!("\n{x} {y}"));
+            }
+            case
+        }))
+    }
+}
+
+/// The organizer and socket counts from the first line of a case.
+fn counts(line: &str) -> Option<(usize, usize)> {
+    let (organizers, sockets) = line.trim().split_once(' ')?;
+    Some((organizers.parse().ok()?, sockets.parse().ok()?))
+}
+
+impl Shrink for Solution {
+    /// Remove cases, then cables, organizers and sockets.
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_counted(
+            input,
+            |line| counts(line).map(|(organizers, sockets)| 2 + organizers + sockets),
+            |case| {
+                let cables: Vec<&str> = case[1].split_whitespace().collect();
+                let (organizers, sockets) = case[2..].split_at(counts(case[0]).unwrap().0);
+                let render = |cables: &[&str], organizers: &[&str], sockets: &[&str]| {
+                    let mut case = format!(
+                        "{} {}\n{}",
+                        organizers.len(),
+                        sockets.len(),
+                        cables.join(" ")
+                    );
+                    for position in organizers.iter().chain(sockets) {
+                        case.push('\n');
+                        case.push_str(position);
+                    }
+                    case
+                };
+
+                let mut candidates: Vec<String> = removals(&cables)
+                    .iter()
+                    .map(|cables| render(cables, organizers, sockets))
+                    .collect();
+                candidates.extend(
+                    removals(organizers)
+                        .iter()
+                        .map(|organizers| render(&cables, organizers, sockets)),
+                );
+                candidates.extend(
+                    removals(sockets)
+                        .iter()
+                        .map(|sockets| render(&cables, organizers, sockets)),
+                );
+                candidates
+            },
+        )
+    }
+}
@@ -1,11 +1,41 @@
-use std::fmt::Display;
-use std::io::Write;
+use std::fmt::Write;
 use std::iter::zip;
 
-use rayon::prelude::*;
-
 use common::*;
 
+mod generate;
+
+const ORGANIZERS: Limit = Limit {
+    what: "organizer count",
+    min: 0,
+    max: u16::BITS as u64,
+    reason: "used organizers are kept in a u16",
+};
+const SOCKETS: Limit = Limit {
+    what: "socket count",
+    min: 0,
+    max: u16::BITS as u64,
+    reason: "used sockets are kept in a u16",
+};
+const CABLES: Limit = Limit {
+    what: "cable count",
+    min: 0,
+    max: u16::BITS as u64,
+    reason: "used cables are kept in a u16",
+};
+const COORDINATE: Limit = Limit {
+    what: "coordinate",
+    min: 0,
+    max: (1 << (usize::BITS / 2 - 1)) - 1,
+    reason: "squared distances are sums of two squared differences in a usize",
+};
+const CABLE_LENGTH: Limit = Limit {
+    what: "cable length",
+    min: 0,
+    max: (1 << (usize::BITS / 2)) - 1,
+    reason: "lengths are squared in a usize",
+};
+
 struct Searcher<'a> {
     /// Matrix where the rows are sockets and the columns are organizers;
     /// matrix[i][j] is the distance from socket i to organizer j.
@@ -18,6 +48,8 @@ struct Searcher<'a> {
 
     best_organizers: u16,
     best_sockets: u16,
+
+    countdown: Countdown,
 }
 
 impl<'a> Searcher<'a> {
@@ -29,10 +61,16 @@ impl<'a> Searcher<'a> {
             best_assigned: 0,
             best_sockets: 0,
             best_organizers: 0,
+
+            countdown: Countdown::start(),
         }
     }
 
     fn backtrack(&mut self, i: usize, used_sockets: u16, used_organizers: u16, used_cables: u16) {
+        if !self.countdown.tick() {
+            return;
+        }
+
         if i == self.matrix.len() {
             let assigned = used_organizers.count_ones() as usize;
             if assigned > self.best_assigned {
@@ -76,143 +114,159 @@ impl<'a> Searcher<'a> {
     }
 }
 
-#[inline]
-pub fn solve() -> impl Display {
-    let mut lines = include_str!("input.txt").lines();
-    let t = lines.next().unwrap().parse::<usize>().unwrap();
-
-    cases(
-        (0..t)
-            .map(|_| {
-                let (organizer_count, socket_count) = {
-                    let mut it = lines.next().unwrap().split_whitespace();
-                    (
-                        it.next().unwrap().parse::<usize>().unwrap(),
-                        it.next().unwrap().parse::<usize>().unwrap(),
-                    )
-                };
-                let cable_lengths = lines
-                    .next()
-                    .unwrap()
-                    .split_whitespace()
-                    .map(|s| s.parse::<usize>().unwrap())
-                    .collect::<Vec<_>>();
-                let organizer_positions = lines
-                    .by_ref()
-                    .take(organizer_count)
-                    .map(|line| {
-                        let mut it = line.split_whitespace();
-                        (
-                            it.next().unwrap().parse::<usize>().unwrap(),
-                            it.next().unwrap().parse::<usize>().unwrap(),
-                        )
-                    })
-                    .collect::<Vec<_>>();
-                let socket_positions = lines
-                    .by_ref()
-                    .take(socket_count)
-                    .map(|line| {
-                        let mut it = line.split_whitespace();
-                        (
-                            it.next().unwrap().parse::<usize>().unwrap(),
-                            it.next().unwrap().parse::<usize>().unwrap(),
-                        )
-                    })
-                    .collect::<Vec<_>>();
-
-                let mut matrix = vec![vec![usize::MAX; organizer_count]; socket_count];
-
-                for (&(u, v), row) in zip(socket_positions.iter(), matrix.iter_mut()) {
-                    for (&(x, y), cell) in zip(organizer_positions.iter(), row.iter_mut()) {
-                        let d = x.abs_diff(u).pow(2) + y.abs_diff(v).pow(2);
-                        *cell = d;
-                    }
-                }
+type Position = (usize, usize);
+
+pub struct Case {
+    cable_lengths: Vec<usize>,
+    organizer_positions: Vec<Position>,
+    socket_positions: Vec<Position>,
+}
+
+/// Summarize how a case went at debug level, and trace the whole search space along with the
+/// best assignment found.
+fn log_case(input: &[Case], case: &Case, matrix: &[Vec<usize>], searcher: &Searcher) {
+    let Case {
+        cable_lengths,
+        organizer_positions,
+        socket_positions,
+    } = case;
+    let n = case_number(input, case);
+
+    log::debug!(
+        "case #{n}: assigned {} of {} organizers to {} sockets with {} cables",
+        searcher.best_assigned,
+        organizer_positions.len(),
+        socket_positions.len(),
+        cable_lengths.len()
+    );
+
+    if log::log_enabled!(log::Level::Trace) {
+        let mut trace = String::new();
+        write!(trace, "case #{n}:\norganizers:").unwrap();
+        for &(x, y) in organizer_positions {
+            write!(trace, " ({x},{y})").unwrap();
+        }
+        write!(trace, "\nsockets:").unwrap();
+        for &(u, v) in socket_positions {
+            write!(trace, " ({u},{v})").unwrap();
+        }
+        write!(trace, "\ndistance matrix:").unwrap();
+        for row in matrix {
+            writeln!(trace).unwrap();
+            for cell in row {
+                write!(trace, "{:7.2}", (*cell as f64).sqrt()).unwrap();
+            }
+        }
+        write!(trace, "\ncable lengths:").unwrap();
+        for length in cable_lengths {
+            write!(trace, " {length}").unwrap();
+        }
+        write!(trace, "\nused organizers:").unwrap();
+        for (i, &(x, y)) in organizer_positions.iter().enumerate() {
+            if searcher.best_organizers & (1 << i) != 0 {
+                write!(trace, " ({x},{y})").unwrap();
+            }
+        }
+        write!(trace, "\nused sockets:").unwrap();
+        for (j, &(u, v)) in socket_positions.iter().enumerate() {
+            if searcher.best_sockets & (1 << j) != 0 {
+                write!(trace, " ({u},{v})").unwrap();
+            }
+        }
+        log::trace!("{trace}");
+    }
+}
 
-                (
-                    matrix,
-                    cable_lengths,
-                    organizer_count,
-                    organizer_positions.clone(),
-                    socket_positions.clone(),
-                )
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'k';
+    const INPUT: &'static str = include_str!("input.txt");
+    const LIMITS: &'static [Limit] = &[ORGANIZERS, SOCKETS, CABLES, COORDINATE, CABLE_LENGTH];
+    const PARALLEL: bool = true;
+
+    type Input = Vec<Case>;
+    type Case = Case;
+    type Output = Searched<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).counted(|scanner| {
+            let (organizer_count, socket_count) = scanner.tuple::<(usize, usize)>()?;
+            let cable_lengths = scanner.line_of()?;
+            let organizer_positions = (0..organizer_count)
+                .map(|_| scanner.tuple())
+                .collect::<Result<_, _>>()?;
+            let socket_positions = (0..socket_count)
+                .map(|_| scanner.tuple())
+                .collect::<Result<_, _>>()?;
+
+            Ok(Case {
+                cable_lengths,
+                organizer_positions,
+                socket_positions,
             })
-            .collect::<Vec<_>>()
-            .into_par_iter()
-            .enumerate()
-            .map(
-                |(
-                    a,
-                    (matrix, cable_lengths, organizer_count, organizer_positions, socket_positions),
-                )| {
-                    let mut searcher = Searcher::new(&matrix, &cable_lengths);
-                    searcher.start();
-
-                    {
-                        let mut stderr = std::io::stderr().lock();
-                        writeln!(stderr, "\x1b[31;1mCase #{a}\x1b[0m").unwrap();
-
-                        writeln!(
-                            stderr,
-                            "\x1b[1mOrganizers ({}):\x1b[0m",
-                            organizer_positions.len()
-                        )
-                        .unwrap();
-                        for &(x, y) in &organizer_positions {
-                            write!(stderr, "({x},{y}) ").unwrap();
-                        }
-                        writeln!(stderr).unwrap();
-
-                        write!(
-                            stderr,
-                            "\x1b[1mSockets ({}):\x1b[0m ",
-                            socket_positions.len()
-                        )
-                        .unwrap();
-                        for &(u, v) in &socket_positions {
-                            write!(stderr, "({u},{v}) ").unwrap();
-                        }
-                        writeln!(stderr).unwrap();
-
-                        write!(stderr, "\x1b[1mDistance matrix:\x1b[0m\n").unwrap();
-                        for row in &matrix {
-                            for cell in row {
-                                write!(stderr, "{:6.2} ", (*cell as f64).sqrt()).unwrap();
-                            }
-                            writeln!(stderr).unwrap();
-                        }
-
-                        writeln!(
-                            stderr,
-                            "\x1b[1mCable lengths ({}):\x1b[0m",
-                            cable_lengths.len()
-                        )
-                        .unwrap();
-                        for length in &cable_lengths {
-                            write!(stderr, "{:4} ", length).unwrap();
-                        }
-                        writeln!(stderr).unwrap();
-
-                        writeln!(stderr, "\x1b[1mBest assignment:\x1b[0m").unwrap();
-                        writeln!(stderr, "Assigned = {}", searcher.best_assigned).unwrap();
-                        writeln!(stderr, "Used organizers:").unwrap();
-                        for (i, &(x, y)) in organizer_positions.iter().enumerate() {
-                            if searcher.best_organizers & (1 << i) != 0 {
-                                write!(stderr, "({x},{y}) ").unwrap();
-                            }
-                        }
-                        writeln!(stderr, "\nUsed sockets:").unwrap();
-                        for (j, &(u, v)) in socket_positions.iter().enumerate() {
-                            if searcher.best_sockets & (1 << j) != 0 {
-                                write!(stderr, "({u},{v}) ").unwrap();
-                            }
-                        }
-                        writeln!(stderr).unwrap();
-                    }
-
-                    organizer_count - searcher.best_assigned
-                },
-            )
-            .collect::<Vec<_>>(),
-    )
+        })
+    }
+
+    fn validate_case(_input: &Self::Input, case: &Case) -> Result<(), LimitError> {
+        ORGANIZERS.check(case.organizer_positions.len())?;
+        SOCKETS.check(case.socket_positions.len())?;
+        CABLES.check(case.cable_lengths.len())?;
+        for &(x, y) in case
+            .organizer_positions
+            .iter()
+            .chain(&case.socket_positions)
+        {
+            COORDINATE.check(x)?;
+            COORDINATE.check(y)?;
+        }
+        for &length in &case.cable_lengths {
+            CABLE_LENGTH.check(length)?;
+        }
+        Ok(())
+    }
+
+    fn solve_case(input: &Self::Input, case: &Case) -> Searched<usize> {
+        let Case {
+            cable_lengths,
+            organizer_positions,
+            socket_positions,
+        } = case;
+        let organizer_count = organizer_positions.len();
+        let socket_count = socket_positions.len();
+
+        let mut matrix = vec![vec![usize::MAX; organizer_count]; socket_count];
+
+        for (&(u, v), row) in zip(socket_positions.iter(), matrix.iter_mut()) {
+            for (&(x, y), cell) in zip(organizer_positions.iter(), row.iter_mut()) {
+                let d = x.abs_diff(u).pow(2) + y.abs_diff(v).pow(2);
+                *cell = d;
+            }
+        }
+
+        let mut searcher = Searcher::new(&matrix, cable_lengths);
+        searcher.start();
+
+        if log::log_enabled!(log::Level::Debug) {
+            log_case(input, case, &matrix, &searcher);
+        }
+
+        searcher
+            .countdown
+            .finish(organizer_count - searcher.best_assigned)
+    }
+
+    fn case_size(_input: &Self::Input, case: &Case) -> Option<usize> {
+        Some(
+            case.organizer_positions.len() + case.socket_positions.len() + case.cable_lengths.len(),
+        )
+    }
+
+    fn optimal(unassigned: &Searched<usize>) -> bool {
+        unassigned.optimal
+    }
+
+    fn format_case(unassigned: &Searched<usize>) -> String {
+        unassigned.best.to_string()
+    }
 }
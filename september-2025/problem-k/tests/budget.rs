@@ -0,0 +1,16 @@
+use common::{Budget, Problem, Solver};
+use september_2025_problem_k::Solution;
+
+#[test]
+fn out_of_budget_cases_still_get_answers() {
+    common::set_budget(Budget {
+        time: None,
+        nodes: Some(1_000),
+    });
+    let run = Solution.run_timed(Solution::INPUT).unwrap();
+    assert!(!run.timed_out.is_empty());
+
+    let expected = include_str!("../output.txt");
+    let report = Solution::check(Solution::INPUT, expected, &run.output).unwrap();
+    assert_eq!(report.cases.len(), run.cases.len());
+}
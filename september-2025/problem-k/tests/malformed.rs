@@ -0,0 +1,36 @@
+use common::{InputError, Problem};
+use september_2025_problem_k::Solution;
+
+/// One case with an organizer at `(coordinate, 0)`, a socket at `(0, coordinate)` and a cable of
+/// `length`.
+fn case(coordinate: u64, length: u64) -> String {
+    format!("1\n1 1\n{length}\n{coordinate} 0\n0 {coordinate}")
+}
+
+#[test]
+fn largest_coordinate() {
+    let max = (1 << (usize::BITS / 2 - 1)) - 1;
+    assert_eq!(Solution::run(&case(max, 1)).unwrap().trim(), "Case #1: 1");
+
+    let Err(InputError::Unsupported(err)) = Solution::parse_valid(&case(max + 1, 1)) else {
+        panic!("input should be unsupported");
+    };
+    assert_eq!(
+        (err.case, err.limit.what, err.found),
+        (Some(1), "coordinate", max + 1)
+    );
+}
+
+#[test]
+fn longest_cable() {
+    let max = (1 << (usize::BITS / 2)) - 1;
+    assert_eq!(Solution::run(&case(0, max)).unwrap().trim(), "Case #1: 0");
+
+    let Err(InputError::Unsupported(err)) = Solution::parse_valid(&case(0, max + 1)) else {
+        panic!("input should be unsupported");
+    };
+    assert_eq!(
+        (err.case, err.limit.what, err.found),
+        (Some(1), "cable length", max + 1)
+    );
+}
@@ -1,9 +1,13 @@
-use std::fmt::Display;
+use common::*;
 
-use indicatif::*;
-use rayon::prelude::*;
+mod generate;
 
-use common::*;
+const N: Limit = Limit {
+    what: "n",
+    min: 1,
+    max: u32::MAX as u64,
+    reason: "the search needs at least one subsequence to build",
+};
 
 /// Actual GEOLYMP string.
 const GEOLYMP: &str = "GEOLYMP";
@@ -216,41 +220,94 @@ impl Searcher {
     }
 }
 
-#[inline]
-pub fn solve() -> impl Display {
-    let input = include_str!("input.txt");
-    let total = input.lines().count();
-    cases(
-        input
-            .par_lines()
-            .progress_count(total as u64)
-            .map(|line| {
-                let n = line.trim().parse::<u32>().unwrap();
-
-                let mut m = n;
-                while m != 0 {
-                    // Start from a base string that corresponds to the highest power of two <= m
-                    let base = build_base(m);
-                    let mut searcher = Searcher::new(n);
-                    if !searcher.search_with_base(&base) {
-                        // If not found, reduce m to the next lower power of two and try again; no
-                        // idea why this works.
-                        m >>= 1;
-                        continue;
-                    };
-
-                    let readable_base = base
-                        .bytes()
-                        .map(|b| GEOLYMP.as_bytes()[b as usize] as char)
-                        .collect::<String>();
-                    return format!(
-                        "{readable_base}{}",
-                        searcher.to_string_with_charset(GEOLYMP)
-                    );
-                }
-                unreachable!();
-            })
-            .collect::<Vec<_>>(),
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'l';
+    const INPUT: &'static str = include_str!("input.txt");
+    const LIMITS: &'static [Limit] = &[N];
+    const PARALLEL: bool = true;
+
+    type Input = Vec<u32>;
+    type Case = u32;
+    type Output = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).until_eof(Scanner::read)
+    }
+
+    fn validate_case(_input: &Self::Input, &n: &u32) -> Result<(), LimitError> {
+        N.check(n)
+    }
+
+    fn solve_case(_input: &Self::Input, &n: &u32) -> String {
+        let mut m = n;
+        while m != 0 {
+            // Start from a base string that corresponds to the highest power of two <= m
+            let base = build_base(m);
+            let mut searcher = Searcher::new(n);
+            if !searcher.search_with_base(&base) {
+                // If not found, reduce m to the next lower power of two and try again; no
+                // idea why this works.
+                m >>= 1;
+                continue;
+            };
+
+            let readable_base = base
+                .bytes()
+                .map(|b| GEOLYMP.as_bytes()[b as usize] as char)
+                .collect::<String>();
+            return format!(
+                "{readable_base}{}",
+                searcher.to_string_with_charset(GEOLYMP)
+            );
+        }
+        unreachable!();
+    }
+
+    fn case_size(_input: &Self::Input, &n: &u32) -> Option<usize> {
+        Some(n as usize)
+    }
+
+    fn format_case(output: &String) -> String {
+        output.clone()
+    }
+
+    fn judge_case(_input: &Self::Input, &n: &u32, _expected: Option<&str>, found: &str) -> Verdict {
+        verdict(judge(n, found))
+    }
+}
+
+impl Reference for Solution {
+    /// `GEOLYM` followed by `n` `P`s has exactly `n` subsequences, but is only short enough for
+    /// `n <= 994`.
+    fn reference_case(_input: &Self::Input, &n: &u32) -> String {
+        format!("{}{}", &GEOLYMP[..GEOLYMP_LEN - 1], "P".repeat(n as usize))
+    }
+}
+
+/// Any string is accepted as long as it is short enough and has exactly `n` GEOLYMP
+/// subsequences.
+fn judge(n: u32, found: &str) -> Result<(), Verdict> {
+    let s = parse_answer(found, Scanner::token)?;
+    if s.len() > MAX_LEN {
+        return Err(reject(format!(
+            "length is {}, more than {MAX_LEN}",
+            s.len()
+        )));
+    }
+
+    // Count with a wider type than the solver does, so overflows there are caught here.
+    let mut prefixes = [0u64; GEOLYMP_LEN];
+    for c in s.bytes() {
+        if let Some(i) = GEOLYMP.bytes().position(|g| g == c) {
+            prefixes[i] += if i == 0 { 1 } else { prefixes[i - 1] };
+        }
+    }
+    check_optimal(
+        "number of GEOLYMP subsequences",
+        u64::from(n),
+        prefixes[GEOLYMP_LEN - 1],
     )
 }
 
@@ -0,0 +1,6 @@
+use september_2025_problem_l::Solution;
+
+#[test]
+fn differential() {
+    common::assert_differential::<Solution, _>((1..=994).map(|n| n.to_string()));
+}
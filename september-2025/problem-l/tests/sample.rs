@@ -0,0 +1,14 @@
+use common::Problem;
+use september_2025_problem_l::Solution;
+
+/// The statement has no sample output ("N/A" heads the sample), so the sample is every `n` below
+/// 4096 instead, each answer checked on its own by the judge.
+#[test]
+fn sample() {
+    let input = include_str!("../src/sample_input.txt");
+    let input = input.strip_prefix("N/A\n").expect("sample starts with N/A");
+    let found = Solution::run(input).unwrap();
+    let report = Solution::check(input, "", &found).unwrap();
+    assert_eq!(report.cases.len(), (1 << 12) - 1);
+    assert!(report.is_accepted(), "{report}");
+}
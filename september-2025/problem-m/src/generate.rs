@@ -0,0 +1,31 @@
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct TriangleConstraints {
+        cases: usize = 18,
+        max_side: u8 = 100,
+        max_cuts: u8 = 10,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = TriangleConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &TriangleConstraints) -> String {
+        counted_input((0..constraints.cases).map(|_| {
+            let a = rng.random_range(1..=constraints.max_side);
+            let b = rng.random_range(1..=constraints.max_side);
+            let k = rng.random_range(1..=constraints.max_cuts);
+            format!("{a} {b} {k}")
+        }))
+    }
+}
+
+impl Shrink for Solution {
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_counted(input, |_| Some(1), |_| Vec::new())
+    }
+}
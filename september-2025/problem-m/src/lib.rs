@@ -1,21 +1,25 @@
-use std::fmt::Display;
-
-use itertools::Itertools;
-
 use common::*;
 
+mod generate;
+
 struct Searcher {
     answer: f64,
+    countdown: Countdown,
 }
 
 impl Searcher {
     fn new() -> Self {
         Self {
             answer: 0.,
+            countdown: Countdown::start(),
         }
     }
 
     fn search(&mut self, a: f64, b: f64, k: u8) {
+        if !self.countdown.tick() {
+            return;
+        }
+
         let area = a * b / 2.0;
         if area < self.answer {
             return;
@@ -37,16 +41,39 @@ impl Searcher {
     }
 }
 
-#[inline]
-pub fn solve() -> impl Display {
-    cases(include_str!("input.txt").lines().skip(1).map(|line| {
-        let (a, b, k) = line
-            .split_whitespace()
-            .map(|n| n.parse::<u8>().unwrap())
-            .collect_tuple()
-            .unwrap();
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = 'm';
+    const INPUT: &'static str = include_str!("input.txt");
+    const COMPARISON: Comparison = Comparison::Float {
+        absolute: 1e-6,
+        relative: 1e-6,
+    };
+
+    type Input = Vec<(u8, u8, u8)>;
+    type Case = (u8, u8, u8);
+    type Output = Searched<f64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).counted(Scanner::tuple)
+    }
+
+    fn solve_case(_input: &Self::Input, &(a, b, k): &(u8, u8, u8)) -> Searched<f64> {
         let mut searcher = Searcher::new();
         searcher.search(a as _, b as _, k);
-        format!("{:.6}", searcher.answer)
-    }))
+        searcher.countdown.finish(searcher.answer)
+    }
+
+    fn case_size(_input: &Self::Input, &(_, _, k): &(u8, u8, u8)) -> Option<usize> {
+        Some(k.into())
+    }
+
+    fn optimal(area: &Searched<f64>) -> bool {
+        area.optimal
+    }
+
+    fn format_case(area: &Searched<f64>) -> String {
+        format!("{:.6}", area.best)
+    }
 }
@@ -0,0 +1,522 @@
+//! Scaffolding and bookkeeping for the contests in the workspace, each a directory of problem
+//! crates sharing `common`, `checker` and `benchmark`.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+
+use chrono::{Local, NaiveDate, NaiveDateTime, TimeDelta};
+use clap::{Parser, Subcommand};
+use owo_colors::OwoColorize;
+use toml_edit::{DocumentMut, Item, Table, value};
+
+const PROBLEM_NAME: &str = "problem-";
+
+const MAIN: &str = "\
+fn main() {
+    common::main(&{crate}::Solution);
+}
+";
+
+const LIB: &str = "\
+use common::*;
+
+mod generate;
+
+pub struct Solution;
+
+impl Problem for Solution {
+    const LETTER: char = '{letter}';
+    const INPUT: &'static str = include_str!(\"input.txt\");
+
+    type Input = Vec<String>;
+    type Case = String;
+    type Output = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ScanError> {
+        Scanner::new(input).until_eof(|scanner| Ok(scanner.line()?.to_owned()))
+    }
+
+    fn solve_case(_input: &Self::Input, _line: &String) -> String {
+        \"TODO\".to_owned()
+    }
+
+    fn format_case(output: &String) -> String {
+        output.clone()
+    }
+}
+";
+
+const GENERATE: &str = "\
+use common::rand::Rng as _;
+use common::*;
+
+use super::Solution;
+
+constraints! {
+    pub struct LineConstraints {
+        lines: usize = 8,
+        max_len: usize = 10,
+    }
+}
+
+impl Generate for Solution {
+    type Constraints = LineConstraints;
+
+    fn generate(rng: &mut Rng, constraints: &LineConstraints) -> String {
+        let lines: Vec<String> = (0..constraints.lines)
+            .map(|_| {
+                let len = rng.random_range(1..=constraints.max_len);
+                random_word(rng, b\"abcdefghijklmnopqrstuvwxyz\", len)
+            })
+            .collect();
+        lines.join(\"\\n\")
+    }
+}
+
+impl Shrink for Solution {
+    fn shrink(input: &str) -> Vec<String> {
+        shrink_lines(input)
+    }
+}
+";
+
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Task,
+}
+
+#[derive(Subcommand)]
+enum Task {
+    /// Start a new contest, in a directory of its own. Problems started afterwards go in it.
+    #[command(alias = "nc")]
+    NewContest { name: String },
+
+    /// Start solving a problem identified by a single character.
+    #[command(alias = "ss")]
+    StartSolve {
+        problem_char: char,
+
+        /// Contest to add the problem to. Defaults to the one the current directory is in, or
+        /// else the latest.
+        #[arg(long)]
+        contest: Option<String>,
+    },
+
+    /// Run problem solution and save output to output.txt
+    SaveOutput,
+
+    /// Compare current output to output.txt
+    CompareOutput,
+
+    /// Set the completion time for the problem you're currently in.
+    #[command(alias = "sct")]
+    SetCompletionTime,
+
+    /// Measure completion time for all problems.
+    #[command(alias = "mct")]
+    MeasureCompletionTime {
+        /// Contest to measure. Defaults to the one the current directory is in, or else the
+        /// latest.
+        #[arg(long)]
+        contest: Option<String>,
+    },
+}
+
+type Result<T = (), E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+fn main() -> ExitCode {
+    let result = match Args::parse().command {
+        Task::NewContest { name } => new_contest(&name),
+        Task::StartSolve {
+            problem_char,
+            contest,
+        } => start_solve(problem_char, contest),
+        Task::SaveOutput => save_output(),
+        Task::CompareOutput => compare_output(),
+        Task::SetCompletionTime => set_completion_time(),
+        Task::MeasureCompletionTime { contest } => measure_completion_time(contest),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{} {err}", "error:".red().bold());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The workspace root, i.e. the parent of this crate.
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn workspace_manifest_path() -> PathBuf {
+    workspace().join("Cargo.toml")
+}
+
+fn read_manifest() -> Result<DocumentMut> {
+    Ok(std::fs::read_to_string(workspace_manifest_path())?.parse()?)
+}
+
+fn write_manifest(manifest: &DocumentMut) -> Result {
+    std::fs::write(workspace_manifest_path(), manifest.to_string())?;
+    Ok(())
+}
+
+/// The `[workspace.metadata]` table, created if missing.
+fn metadata(manifest: &mut DocumentMut) -> &mut Table {
+    let metadata = manifest["workspace"]
+        .as_table_mut()
+        .expect("workspace manifest has no [workspace] table")
+        .entry("metadata")
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        });
+    metadata
+        .as_table_mut()
+        .expect("[workspace.metadata] is not a table")
+}
+
+/// The shared crates, which no contest may be named after.
+const SHARED: [&str; 5] = ["benchmark", "checker", "common", "tasks", "target"];
+
+/// The contests recorded in the workspace manifest, oldest first.
+fn contests(manifest: &mut DocumentMut) -> Vec<String> {
+    metadata(manifest)
+        .iter()
+        .filter(|(_, item)| item.is_table_like())
+        .map(|(name, _)| name.to_owned())
+        .collect()
+}
+
+/// The contest named `name`, or else the one the current directory is in, or else the latest.
+fn contest(manifest: &mut DocumentMut, name: Option<String>) -> Result<String> {
+    let contests = contests(manifest);
+    if let Some(name) = name {
+        if !contests.contains(&name) {
+            return Err(format!(
+                "no such contest: {name:?}; start it with `cargo tasks new-contest {name}`"
+            )
+            .into());
+        }
+        return Ok(name);
+    }
+    let cwd = std::env::current_dir()?.canonicalize()?;
+    let current = contests
+        .iter()
+        .find(|contest| cwd.starts_with(workspace().join(contest)));
+    current
+        .or(contests.last())
+        .cloned()
+        .ok_or_else(|| "no contest yet; start one with `cargo tasks new-contest NAME`".into())
+}
+
+/// The table of a contest's problems in `[workspace.metadata]`.
+fn contest_metadata<'a>(manifest: &'a mut DocumentMut, contest: &str) -> &'a mut Table {
+    metadata(manifest)
+        .entry(contest)
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .expect("contest metadata is not a table")
+}
+
+/// The current local time, as a TOML datetime.
+fn now() -> toml_edit::Datetime {
+    Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M:%S%.6f")
+        .to_string()
+        .parse()
+        .unwrap()
+}
+
+fn to_naive(datetime: &toml_edit::Datetime) -> Option<NaiveDateTime> {
+    let date = datetime.date?;
+    let time = datetime.time?;
+    NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())?.and_hms_nano_opt(
+        time.hour.into(),
+        time.minute.into(),
+        time.second.into(),
+        time.nanosecond,
+    )
+}
+
+/// Format a duration like Python's `timedelta`, e.g. `3 days, 8:21:59.299275`.
+fn format_elapsed(elapsed: TimeDelta) -> String {
+    let days = elapsed.num_days();
+    let rest = elapsed - TimeDelta::days(days);
+    let seconds = rest.num_seconds();
+    let micros = rest.subsec_nanos() / 1000;
+
+    let mut result = String::new();
+    if days != 0 {
+        let plural = if days.abs() == 1 { "" } else { "s" };
+        result.push_str(&format!("{days} day{plural}, "));
+    }
+    result.push_str(&format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    ));
+    if micros != 0 {
+        result.push_str(&format!(".{micros:06}"));
+    }
+    result
+}
+
+/// Echo and run a command, failing if it does.
+fn run(command: &mut Command) -> Result<std::process::Output> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let args = command
+        .get_args()
+        .map(|arg| arg.to_string_lossy().into_owned());
+    println!(
+        "{} {}",
+        "$".green().bold(),
+        std::iter::once(program)
+            .chain(args)
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+
+    let output = command.output()?;
+    if !output.status.success() {
+        println!("{}", "Failed.".red().bold());
+        return Err(format!("command exited with {}", output.status).into());
+    }
+    Ok(output)
+}
+
+fn new_contest(name: &str) -> Result {
+    let valid = name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid || SHARED.contains(&name) {
+        return Err(format!(
+            "invalid contest name {name:?}; use lowercase letters, digits and dashes, e.g. \
+             `october-2025`"
+        )
+        .into());
+    }
+
+    let mut manifest = read_manifest()?;
+    if contests(&mut manifest)
+        .iter()
+        .any(|contest| contest == name)
+    {
+        println!("{name} already exists.");
+        return Ok(());
+    }
+    println!("Starting contest: {name}");
+
+    std::fs::create_dir_all(workspace().join(name))?;
+    // An explicit table, so that the contest is recorded before it has any problems.
+    metadata(&mut manifest).insert(name, Item::Table(Table::new()));
+    write_manifest(&manifest)
+}
+
+fn start_solve(problem_char: char, contest: Option<String>) -> Result {
+    let letter = problem_char.to_ascii_lowercase();
+    let mut manifest = read_manifest()?;
+    let contest = self::contest(&mut manifest, contest)?;
+    println!("Starting to solve problem: {problem_char} of {contest}");
+
+    let problem = format!("{PROBLEM_NAME}{letter}");
+    // Named after the contest too, since package names must be unique across the workspace.
+    let crate_name = format!("{contest}-{problem}");
+    let crate_ident = crate_name.replace('-', "_");
+    let member = format!("{contest}/{problem}");
+    let crate_path = workspace().join(&member);
+
+    if crate_path.exists() {
+        println!("{member} already exists.");
+        return Ok(());
+    }
+
+    let members = manifest["workspace"]["members"]
+        .as_array_mut()
+        .expect("workspace.members is not an array");
+    if !members
+        .iter()
+        .any(|existing| existing.as_str() == Some(&member))
+    {
+        members.push(&member);
+    }
+    let mut times = Table::new();
+    times.insert("start_time", value(now()));
+    contest_metadata(&mut manifest, &contest).insert(&problem, Item::Table(times));
+    write_manifest(&manifest)?;
+
+    let cargo = || {
+        let mut command = Command::new("cargo");
+        command.current_dir(workspace());
+        command
+    };
+
+    run(cargo().args(["new", "--bin", "--name", &crate_name, &member]))?;
+
+    let src = crate_path.join("src");
+    std::fs::write(src.join("main.rs"), MAIN.replace("{crate}", &crate_ident))?;
+    std::fs::write(
+        src.join("lib.rs"),
+        LIB.replace("{letter}", &letter.to_string()),
+    )?;
+    // Every problem needs a generator and a shrinker to be registered with the benchmark crate.
+    std::fs::write(src.join("generate.rs"), GENERATE)?;
+    // Placeholders so the workspace keeps building until the real input, the statement's sample
+    // and their outputs are pasted in; benchmark's golden and sample tests fail until then.
+    for placeholder in ["input.txt", "sample_input.txt", "sample_output.txt"] {
+        std::fs::write(src.join(placeholder), "")?;
+    }
+    std::fs::write(crate_path.join("output.txt"), "")?;
+
+    run(cargo().args([
+        "add",
+        "--manifest-path",
+        &format!("{member}/Cargo.toml"),
+        "--path",
+        "common",
+        "common",
+    ]))?;
+
+    run(Command::new("git")
+        .current_dir(workspace())
+        .args(["add", &member]))?;
+
+    Ok(())
+}
+
+/// Run the solution of the problem in the current directory, returning its trimmed output.
+fn run_solution() -> Result<String> {
+    let output = run(Command::new("cargo")
+        .args(["run", "--release"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit()))?;
+    Ok(String::from_utf8(output.stdout)?.trim().to_owned())
+}
+
+fn save_output() -> Result {
+    let output = run_solution()?;
+    std::fs::write("output.txt", output)?;
+    Ok(())
+}
+
+fn compare_output() -> Result {
+    let Some((contest, problem)) = current_problem()? else {
+        return Ok(());
+    };
+    let letter = problem.trim_start_matches(PROBLEM_NAME);
+
+    // The checker knows how each problem's answers should be compared, e.g. with a float
+    // tolerance, so let the runner do the work.
+    let status = run(Command::new("cargo")
+        .args([
+            "run",
+            "--quiet",
+            "--release",
+            "--package",
+            "benchmark",
+            "--bin",
+            "ev",
+        ])
+        .args(["--", "--contest", &contest, "--check", letter])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit()));
+
+    match status {
+        Ok(_) => {
+            println!("{}", "Output matches expected output.".green().bold());
+            Ok(())
+        }
+        Err(err) => {
+            println!("{}", "Output does not match expected output.".red().bold());
+            Err(err)
+        }
+    }
+}
+
+/// The contest and directory name of the problem crate the current directory is in, if any.
+fn current_problem() -> Result<Option<(String, String)>> {
+    let cwd = std::env::current_dir()?.canonicalize()?;
+    let name = |path: &Path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_owned()
+    };
+    let problem = name(&cwd);
+    let contest = cwd.parent().filter(|dir| dir.parent() == Some(workspace()));
+    let Some(contest) = contest.filter(|_| problem.starts_with(PROBLEM_NAME)) else {
+        println!(
+            "{}",
+            format!("Not in a contest's {PROBLEM_NAME} directory.")
+                .red()
+                .bold()
+        );
+        return Ok(None);
+    };
+    Ok(Some((name(contest), problem)))
+}
+
+fn set_completion_time() -> Result {
+    let Some((contest, problem)) = current_problem()? else {
+        return Ok(());
+    };
+
+    let mut manifest = read_manifest()?;
+    let times = contest_metadata(&mut manifest, &contest)
+        .entry(&problem)
+        .or_insert_with(|| Item::Table(Table::new()));
+    times["completion_time"] = value(now());
+    write_manifest(&manifest)
+}
+
+fn measure_completion_time(contest: Option<String>) -> Result {
+    let mut manifest = read_manifest()?;
+    let contest = self::contest(&mut manifest, contest)?;
+    println!("{}", contest.bold());
+    let metadata = contest_metadata(&mut manifest, &contest);
+
+    let mut problems: Vec<String> = std::fs::read_dir(workspace().join(&contest))?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.starts_with(PROBLEM_NAME))
+        .collect();
+    problems.sort();
+
+    let rows: Vec<(String, String)> = problems
+        .into_iter()
+        .map(|problem| {
+            let time = |key| {
+                metadata
+                    .get(&problem)?
+                    .get(key)?
+                    .as_datetime()
+                    .and_then(to_naive)
+            };
+            let completion_time = match (time("start_time"), time("completion_time")) {
+                (Some(start), Some(end)) => format_elapsed(end - start),
+                _ => "N/A".to_owned(),
+            };
+            (problem, completion_time)
+        })
+        .collect();
+
+    let header = ("Problem", "Completion Time");
+    let width = rows
+        .iter()
+        .map(|(problem, _)| problem.len())
+        .chain([header.0.len()])
+        .max()
+        .unwrap();
+    println!("{:<width$}  {}", header.0.bold(), header.1.bold());
+    for (problem, completion_time) in rows {
+        println!("{problem:<width$}  {completion_time}");
+    }
+    Ok(())
+}